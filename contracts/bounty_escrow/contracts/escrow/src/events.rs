@@ -22,8 +22,106 @@
 //! │       └─────→ Refund  → FundsRefunded                       │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
+//!
+//! ## Event Envelope
+//!
+//! Every event's data is a `(EventEnvelope, payload)` pair. The envelope
+//! layout is shared with the program escrow and core contracts and is
+//! documented in `backend/EVENT_INDEXING_STRATEGY.md`; this contract's kind
+//! is `bounty`. The per-event documentation below describes the payload.
+
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, IntoVal, String, Symbol, Topics, Val, Vec,
+};
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+/// Kind reported in the envelope of this contract's events.
+const CONTRACT_KIND: Symbol = symbol_short!("bounty");
+
+// ============================================================================
+// Event Envelope
+// ============================================================================
+//
+// This section is kept word-for-word identical in the bounty escrow, program
+// escrow and core `events.rs`; only `CONTRACT_KIND` above it differs. The
+// layout is documented in `backend/EVENT_INDEXING_STRATEGY.md`.
+
+/// Version of the envelope and payload layout, shared by every Grainlify
+/// contract. Bump it when any contract's payload changes shape.
+///
+/// * `1` - Initial envelope.
+/// * `2` - Program escrow `FundLock` payload gained the `funder` address.
+/// * `3` - Program escrow `pause`/`unpause` payloads became
+///   `(admin, timestamp)`.
+/// * `4` - Bounty escrow `FeeCollected` and `FeeConfigUpdated` no longer
+///   carry a fee recipient; fees go to `splits` or the contract's fee ledger.
+pub const EVENT_SCHEMA_VERSION: u32 = 4;
+
+/// Metadata wrapped around every event payload: events are published with
+/// `(EventEnvelope, payload)` as their data.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventEnvelope {
+    pub schema_version: u32,
+    pub contract_kind: Symbol,
+    pub sequence: u64,
+    pub correlation_id: Option<String>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum EnvelopeKey {
+    Sequence,
+    CorrelationId,
+}
+
+/// Returns the sequence number of the last event emitted, or zero.
+pub fn get_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&EnvelopeKey::Sequence)
+        .unwrap_or(0)
+}
+
+/// Runs `f` with `correlation_id` attached to every event it publishes.
+///
+/// The id is held in temporary storage only while `f` runs, so it never
+/// leaks into the events of a later, unrelated call.
+pub fn with_correlation_id<R>(
+    env: &Env,
+    correlation_id: Option<String>,
+    f: impl FnOnce() -> R,
+) -> R {
+    if let Some(id) = correlation_id {
+        env.storage()
+            .temporary()
+            .set(&EnvelopeKey::CorrelationId, &id);
+    }
+    let result = f();
+    env.storage()
+        .temporary()
+        .remove(&EnvelopeKey::CorrelationId);
+    result
+}
+
+/// Publishes `data` wrapped in the next event envelope.
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    let sequence = get_sequence(env) + 1;
+    env.storage()
+        .instance()
+        .set(&EnvelopeKey::Sequence, &sequence);
+
+    let envelope = EventEnvelope {
+        schema_version: EVENT_SCHEMA_VERSION,
+        contract_kind: CONTRACT_KIND,
+        sequence,
+        correlation_id: env.storage().temporary().get(&EnvelopeKey::CorrelationId),
+    };
+    env.events().publish(topics, (envelope, data.into_val(env)));
+}
 
 // ============================================================================
 // Contract Initialization Event
@@ -75,7 +173,7 @@ pub struct BountyEscrowInitialized {
 /// Data: Complete `BountyEscrowInitialized` struct
 pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
     let topics = (symbol_short!("init"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 // ============================================================================
@@ -89,6 +187,8 @@ pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
 /// * `amount` - Amount of tokens locked (in stroops for XLM)
 /// * `depositor` - Address that deposited the funds
 /// * `deadline` - Unix timestamp after which refunds are allowed
+/// * `token` - Token contract the funds are denominated in
+/// * `initiator` - Address that created the lock (depositor or lock operator)
 ///
 /// # Event Topic
 /// Symbol: `f_lock`
@@ -113,7 +213,7 @@ pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
 /// ```rust
 /// // Lock 1000 XLM for bounty #42, deadline in 30 days
 /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60);
-/// escrow_client.lock_funds(&depositor, &42, &10_000_000_000, &deadline);
+/// escrow_client.lock_funds(&depositor, &42, &10_000_000_000, &deadline, &xlm);
 /// // → Emits FundsLocked event
 /// ```
 #[contracttype]
@@ -123,6 +223,8 @@ pub struct FundsLocked {
     pub amount: i128,
     pub depositor: Address,
     pub deadline: u64,
+    pub token: Address,
+    pub initiator: Address,
 }
 
 /// Emits a FundsLocked event.
@@ -139,7 +241,7 @@ pub struct FundsLocked {
 /// The bounty_id is included in topics for efficient filtering
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
     let topics = (symbol_short!("f_lock"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 // ============================================================================
@@ -153,6 +255,7 @@ pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
 /// * `amount` - Amount transferred to recipient
 /// * `recipient` - Address receiving the funds (contributor)
 /// * `timestamp` - Unix timestamp of release
+/// * `token` - Token contract the funds are denominated in
 ///
 /// # Event Topic
 /// Symbol: `f_rel`
@@ -193,6 +296,7 @@ pub struct FundsReleased {
     pub amount: i128,
     pub recipient: Address,
     pub timestamp: u64,
+    pub token: Address,
 }
 
 /// Emits a FundsReleased event.
@@ -206,7 +310,7 @@ pub struct FundsReleased {
 /// Data: Complete `FundsReleased` struct
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
     let topics = (symbol_short!("f_rel"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 // ============================================================================
@@ -271,6 +375,7 @@ pub struct FundsRefunded {
     pub timestamp: u64,
     pub refund_mode: crate::RefundMode,
     pub remaining_amount: i128,
+    pub token: Address,
 }
 
 /// Emits a FundsRefunded event.
@@ -284,7 +389,7 @@ pub struct FundsRefunded {
 /// Data: Complete `FundsRefunded` struct
 pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
     let topics = (symbol_short!("f_ref"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -292,6 +397,7 @@ pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
 pub enum FeeOperationType {
     Lock,
     Release,
+    Cancel,
 }
 
 #[contracttype]
@@ -300,13 +406,24 @@ pub struct FeeCollected {
     pub operation_type: FeeOperationType,
     pub amount: i128,
     pub fee_rate: i128,
-    pub recipient: Address,
+    pub token: Address,
+    pub splits: Vec<FeeShare>,
     pub timestamp: u64,
 }
 
+/// One recipient's part of a collected fee. `FeeCollected.splits` lists the
+/// shares credited to addresses (fee split recipients and the referrer); the
+/// part that went to the contract's fee ledger is not listed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeShare {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
 pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
     let topics = (symbol_short!("fee"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -319,7 +436,7 @@ pub struct BatchFundsLocked {
 
 pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
     let topics = (symbol_short!("b_lock"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -327,14 +444,14 @@ pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
 pub struct FeeConfigUpdated {
     pub lock_fee_rate: i128,
     pub release_fee_rate: i128,
-    pub fee_recipient: Address,
     pub fee_enabled: bool,
+    pub cancel_fee_rate: i128,
     pub timestamp: u64,
 }
 
 pub fn emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
     let topics = (symbol_short!("fee_cfg"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -347,7 +464,7 @@ pub struct BatchFundsReleased {
 
 pub fn emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
     let topics = (symbol_short!("b_rel"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 // ============================================================================
 // Contract Pause Events
@@ -363,7 +480,7 @@ pub struct ContractPaused {
 
 pub fn emit_contract_paused(env: &Env, event: ContractPaused) {
     let topics = (symbol_short!("pause"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 /// Event emitted when the contract is unpaused.
@@ -376,7 +493,7 @@ pub struct ContractUnpaused {
 
 pub fn emit_contract_unpaused(env: &Env, event: ContractUnpaused) {
     let topics = (symbol_short!("unpause"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 /// Event emitted when emergency withdrawal occurs.
@@ -384,6 +501,7 @@ pub fn emit_contract_unpaused(env: &Env, event: ContractUnpaused) {
 #[derive(Clone, Debug)]
 pub struct EmergencyWithdrawal {
     pub withdrawn_by: Address,
+    pub token: Address,
     pub amount: i128,
     pub recipient: Address,
     pub timestamp: u64,
@@ -391,5 +509,554 @@ pub struct EmergencyWithdrawal {
 
 pub fn emit_emergency_withdrawal(env: &Env, event: EmergencyWithdrawal) {
     let topics = (symbol_short!("ewith"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
+}
+
+// ============================================================================
+// Cancellation Events
+// ============================================================================
+
+/// Event emitted when a bounty is cancelled by the depositor with admin
+/// co-signature.
+///
+/// # Fields
+/// * `refund_amount` - Amount returned to the depositor (after fee)
+/// * `fee_amount` - Cancellation fee sent to the fee recipient
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyCancelled {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub refund_amount: i128,
+    pub fee_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_cancelled(env: &Env, event: BountyCancelled) {
+    let topics = (symbol_short!("cancel"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+// ============================================================================
+// Escrow Ownership Events
+// ============================================================================
+
+/// Event emitted when an escrow is handed over to a new depositor.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EscrowOwnershipTransferred {
+    pub bounty_id: u64,
+    pub previous_depositor: Address,
+    pub new_depositor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_ownership_transferred(env: &Env, event: EscrowOwnershipTransferred) {
+    let topics = (symbol_short!("own_xfer"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the depositor sets or clears the refund address.
+/// `refund_to` is `None` when refunds revert to the depositor.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundAddressUpdated {
+    pub bounty_id: u64,
+    pub refund_to: Option<Address>,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_address_updated(env: &Env, event: RefundAddressUpdated) {
+    let topics = (symbol_short!("ref_addr"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+// ============================================================================
+// Delayed Release Events
+// ============================================================================
+
+/// Event emitted when the admin starts a delayed release.
+///
+/// The release can be finalized by anyone once `finalize_after` has passed,
+/// unless the depositor challenges it first.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseInitiated {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub finalize_after: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_initiated(env: &Env, event: ReleaseInitiated) {
+    let topics = (symbol_short!("rel_init"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the depositor objects to a pending release.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseChallenged {
+    pub bounty_id: u64,
+    pub challenged_by: Address,
+    pub contributor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_challenged(env: &Env, event: ReleaseChallenged) {
+    let topics = (symbol_short!("rel_chal"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the admin resolves a disputed release.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DisputeResolved {
+    pub bounty_id: u64,
+    pub resolved_by: Address,
+    pub released: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("disp_res"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+// ============================================================================
+// Contest Events
+// ============================================================================
+
+/// Event emitted when a contributor enters a contest bounty.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SubmissionRegistered {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_submission_registered(env: &Env, event: SubmissionRegistered) {
+    let topics = (symbol_short!("c_submit"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted for each winner paid when a contest is finalized.
+///
+/// # Fields
+/// * `rank` - 1-based position in the final ranking
+/// * `amount` - Net amount paid to the winner (after release fee)
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContestPrizePaid {
+    pub bounty_id: u64,
+    pub rank: u32,
+    pub winner: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_contest_prize_paid(env: &Env, event: ContestPrizePaid) {
+    let topics = (symbol_short!("c_prize"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted once a contest has been fully paid out.
+///
+/// # Fields
+/// * `awarded` - Gross amount paid to winners (before release fees)
+/// * `refunded` - Unawarded remainder returned to the depositor
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContestFinalized {
+    pub bounty_id: u64,
+    pub finalized_by: Address,
+    pub winners: u32,
+    pub awarded: i128,
+    pub refunded: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_contest_finalized(env: &Env, event: ContestFinalized) {
+    let topics = (symbol_short!("c_final"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+// ============================================================================
+// Token Allowlist Events
+// ============================================================================
+
+/// Event emitted when a token is added to or removed from the allowlist.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenAllowlistUpdated {
+    pub token: Address,
+    pub accepted: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_token_allowlist_updated(env: &Env, event: TokenAllowlistUpdated) {
+    let topics = (symbol_short!("tok_list"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the admin registers or removes a lock operator.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LockOperatorUpdated {
+    pub operator: Address,
+    pub allowed: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_lock_operator_updated(env: &Env, event: LockOperatorUpdated) {
+    let topics = (symbol_short!("lock_op"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the admin updates the lock policy.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LockPolicyUpdated {
+    pub min_deadline_horizon: u64,
+    pub max_deadline_horizon: u64,
+    pub min_amount: i128,
+    pub max_amount: i128,
+    pub max_locked_per_depositor: Option<i128>,
+    pub timestamp: u64,
+}
+
+pub fn emit_lock_policy_updated(env: &Env, event: LockPolicyUpdated) {
+    let topics = (symbol_short!("lock_pol"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the admin freezes a single bounty.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyFrozen {
+    pub bounty_id: u64,
+    pub reason_code: u32,
+    pub frozen_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_frozen(env: &Env, event: BountyFrozen) {
+    let topics = (symbol_short!("frozen"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a bounty freeze is lifted. Carries the reason code the
+/// bounty was frozen with.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyUnfrozen {
+    pub bounty_id: u64,
+    pub reason_code: u32,
+    pub unfrozen_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_unfrozen(env: &Env, event: BountyUnfrozen) {
+    let topics = (symbol_short!("unfrozen"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when an address is added to or removed from the denylist.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DenylistUpdated {
+    pub address: Address,
+    pub denylisted: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_denylist_updated(env: &Env, event: DenylistUpdated) {
+    let topics = (symbol_short!("denylist"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when accrued fees are withdrawn from the contract.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FeesWithdrawn {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub withdrawn_by: Address,
+    pub remaining: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_fees_withdrawn(env: &Env, event: FeesWithdrawn) {
+    let topics = (symbol_short!("fee_wd"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the fee split is changed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FeeSplitsUpdated {
+    pub splits: Vec<crate::FeeSplit>,
+    pub timestamp: u64,
+}
+
+pub fn emit_fee_splits_updated(env: &Env, event: FeeSplitsUpdated) {
+    let topics = (symbol_short!("fee_split"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a recipient claims its fee share.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FeesClaimed {
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_fees_claimed(env: &Env, event: FeesClaimed) {
+    let topics = (symbol_short!("fee_claim"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a referrer is credited with part of a bounty's fee.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReferralReward {
+    pub bounty_id: u64,
+    pub referrer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub operation_type: FeeOperationType,
+    pub timestamp: u64,
+}
+
+pub fn emit_referral_reward(env: &Env, event: ReferralReward) {
+    let topics = (symbol_short!("referral"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a bounty is released as a linear stream.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamStarted {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub total: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+pub fn emit_stream_started(env: &Env, event: StreamStarted) {
+    let topics = (symbol_short!("strm_beg"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a contributor withdraws from a stream. `amount` is net
+/// of the release fee; `withdrawn_total` is the gross withdrawn so far.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamWithdrawn {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub withdrawn_total: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_stream_withdrawn(env: &Env, event: StreamWithdrawn) {
+    let topics = (symbol_short!("strm_wd"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the depositor stops a stream.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StreamStopped {
+    pub bounty_id: u64,
+    pub paid_to_contributor: i128,
+    pub refunded: i128,
+    pub refund_to: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_stream_stopped(env: &Env, event: StreamStopped) {
+    let topics = (symbol_short!("strm_stop"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when the admin sets the trusted KYC attester.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AttesterUpdated {
+    pub attester: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_attester_updated(env: &Env, event: AttesterUpdated) {
+    let topics = (symbol_short!("kyc_attr"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when an attestation is recorded or renewed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AttestationRecorded {
+    pub subject: Address,
+    pub level: u32,
+    pub expires_at: u64,
+    pub attester: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_attestation_recorded(env: &Env, event: AttestationRecorded) {
+    let topics = (symbol_short!("kyc_att"), event.subject.clone());
+    publish(env, topics, event);
+}
+
+/// Event emitted when the attester revokes an attestation.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AttestationRevoked {
+    pub subject: Address,
+    pub attester: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_attestation_revoked(env: &Env, event: AttestationRevoked) {
+    let topics = (symbol_short!("kyc_rev"), event.subject.clone());
+    publish(env, topics, event);
+}
+
+/// Event emitted when the KYC release policy is set or cleared. A cleared
+/// policy is reported with `enabled` false.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct KycPolicyUpdated {
+    pub enabled: bool,
+    pub min_level: u32,
+    pub threshold: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_kyc_policy_updated(env: &Env, event: KycPolicyUpdated) {
+    let topics = (symbol_short!("kyc_pol"),);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a boost reserve is attached to a bounty.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BoostAdded {
+    pub bounty_id: u64,
+    pub reserve: i128,
+    pub rate_per_day: i128,
+    pub curve: crate::BoostCurve,
+    pub start_time: u64,
+}
+
+pub fn emit_boost_added(env: &Env, event: BoostAdded) {
+    let topics = (symbol_short!("boost"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a boosted bounty is released: `accrued` is paid to the
+/// contributor with the base amount, `returned` goes back to `returned_to`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BoostSettled {
+    pub bounty_id: u64,
+    pub accrued: i128,
+    pub returned: i128,
+    pub returned_to: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_boost_settled(env: &Env, event: BoostSettled) {
+    let topics = (symbol_short!("boost_set"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when an applicant stakes to apply to a bounty.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StakeDeposited {
+    pub bounty_id: u64,
+    pub applicant: Address,
+    pub amount: i128,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_stake_deposited(env: &Env, event: StakeDeposited) {
+    let topics = (symbol_short!("stk_dep"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when an application stake is returned to the applicant.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StakeReturned {
+    pub bounty_id: u64,
+    pub applicant: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_stake_returned(env: &Env, event: StakeReturned) {
+    let topics = (symbol_short!("stk_ret"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when an application stake is slashed. `recipient` is the
+/// contract itself when the stake went to the fee ledger.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct StakeSlashed {
+    pub bounty_id: u64,
+    pub applicant: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_stake_slashed(env: &Env, event: StakeSlashed) {
+    let topics = (symbol_short!("stk_slash"), event.bounty_id);
+    publish(env, topics, event);
+}
+
+/// Event emitted when a payout is recorded on a contributor's completion
+/// receipt. `amount` is this payout; `total` is the receipt's running total.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReceiptRecorded {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub total: i128,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_receipt_recorded(env: &Env, event: ReceiptRecorded) {
+    let topics = (symbol_short!("receipt"), event.contributor.clone());
+    publish(env, topics, event);
+}
+
+/// Event emitted when the contract's WASM is upgraded. `version` is the
+/// stored version at the time of the upgrade.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContractUpgraded {
+    pub version: u32,
+    pub new_wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_upgraded(env: &Env, event: ContractUpgraded) {
+    let topics = (symbol_short!("upgraded"),);
+    publish(env, topics, event);
 }
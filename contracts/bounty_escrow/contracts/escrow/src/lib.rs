@@ -73,7 +73,7 @@
 //! let depositor = Address::from_string("GDEPOSIT...");
 //! let amount = 1000_0000000; // 1000 USDC (7 decimals)
 //! let deadline = current_timestamp + (30 * 24 * 60 * 60); // 30 days
-//! escrow_client.lock_funds(&depositor, &42, &amount, &deadline);
+//! escrow_client.lock_funds(&depositor, &42, &amount, &deadline, &token);
 //!
 //! // 3a. Admin releases to contributor (happy path)
 //! let contributor = Address::from_string("GCONTRIB...");
@@ -98,8 +98,8 @@ use events::{
     FundsRefunded, FundsReleased,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN,
+    Env, String, Vec,
 };
 
 // ==================== MONITORING MODULE ====================
@@ -184,7 +184,8 @@ mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
                 operation,
@@ -208,7 +209,8 @@ mod monitoring {
             .persistent()
             .set(&time_key, &(total + duration));
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
                 function,
@@ -396,7 +398,8 @@ mod anti_abuse {
                     .last_operation_timestamp
                     .saturating_add(config.cooldown_period)
         {
-            env.events().publish(
+            crate::events::publish(
+                env,
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
@@ -415,7 +418,8 @@ mod anti_abuse {
         } else {
             // Same window
             if state.operation_count >= config.max_operations {
-                env.events().publish(
+                crate::events::publish(
+                    env,
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
@@ -463,15 +467,81 @@ pub enum Error {
     /// Returned when contract is paused and operation is blocked
     ContractPaused = 11,
     DuplicateBountyId = 12,
-    /// Returned when amount is invalid (zero, negative, or exceeds available)
+    /// Returned when amount is invalid (zero, negative, exceeds available, or
+    /// outside the lock policy bounds)
     InvalidAmount = 13,
-    /// Returned when deadline is invalid (in the past or too far in the future)
+    /// Returned when deadline is outside the lock policy's allowed horizon
     InvalidDeadline = 14,
     /// Returned when contract has insufficient funds for the operation
     InsufficientFunds = 16,
     /// Returned when refund is attempted without admin approval
     RefundNotApproved = 17,
     BatchSizeMismatch = 18,
+    /// Returned when a delayed release is already pending for the bounty
+    ReleasePending = 19,
+    /// Returned when finalizing or challenging a bounty with no pending release
+    NoPendingRelease = 20,
+    /// Returned when finalizing a release before its challenge window has closed
+    ChallengePeriodActive = 21,
+    /// Returned when the depositor objects after the challenge window has closed
+    ChallengePeriodEnded = 22,
+    /// Returned when `release_funds` is used on a bounty that requires a delayed release
+    DelayedReleaseRequired = 23,
+    /// Returned when resolving a dispute on a bounty that is not disputed
+    NotDisputed = 24,
+    /// Returned when a contest prize table is empty, too long, or exceeds 100%
+    InvalidPrizeTable = 25,
+    /// Returned when a contest operation targets a regular bounty
+    NotContest = 26,
+    /// Returned when a single-winner release targets a contest bounty
+    ContestBounty = 27,
+    /// Returned when a contributor submits to the same contest twice
+    DuplicateSubmission = 28,
+    /// Returned when a ranked winner has no submission for the contest
+    SubmissionNotFound = 29,
+    /// Returned when submitting to a contest after its deadline
+    SubmissionsClosed = 30,
+    /// Returned when locking funds in a token that is not on the allowlist
+    TokenNotAccepted = 31,
+    /// Returned when a sponsor's token allowance does not cover an operator lock
+    InsufficientAllowance = 32,
+    /// Returned when a lock would take a depositor past the per-depositor cap
+    DepositorCapExceeded = 33,
+    /// Returned when releasing, refunding or claiming a frozen bounty
+    BountyFrozen = 34,
+    /// Returned when unfreezing a bounty that is not frozen
+    BountyNotFrozen = 35,
+    /// Returned when a depositor, contributor or refund recipient is on the denylist
+    AddressDenylisted = 36,
+    /// Returned when withdrawing more fees than have accrued
+    InsufficientAccruedFees = 37,
+    /// Returned when fee split weights are empty, too many, or do not sum to 10,000
+    InvalidFeeSplit = 38,
+    /// Returned when a depositor names itself as referrer
+    InvalidReferrer = 39,
+    /// Returned when a stream has an empty or already elapsed time range
+    InvalidStreamWindow = 40,
+    /// Returned when a stream operation targets an escrow that is not streaming
+    NotStreaming = 41,
+    /// Returned when a payout above the KYC threshold goes to an address
+    /// without a valid attestation
+    AttestationRequired = 42,
+    /// Returned when a boosted bounty is used with a payout mode that does
+    /// not support boosts
+    BoostedBounty = 43,
+    /// Returned when applying to a bounty that has no stake requirement
+    StakeNotRequired = 44,
+    /// Returned when an applicant has no active stake on the bounty
+    StakeNotActive = 45,
+    /// Returned when an applicant already holds an active stake on the bounty
+    AlreadyApplied = 46,
+    /// Returned when an applicant withdraws a stake while the bounty is still open
+    BountyStillOpen = 48,
+    /// Returned when refunding or cancelling a contest that is still being
+    /// judged (before its deadline plus `CONTEST_JUDGING_WINDOW`)
+    ContestNotFinalized = 49,
+    /// Returned when finalizing a contest before its submission deadline
+    SubmissionsOpen = 50,
 }
 
 // ============================================================================
@@ -483,14 +553,19 @@ pub enum Error {
 /// # State Transitions
 /// ```text
 /// NONE → Locked → Released (final)
+///           ↓  ↑
+///           ↓  Disputed (depositor challenged a delayed release)
 ///           ↓
-///        Refunded (final)
+///        Refunded / Cancelled (final)
 /// ```
 ///
 /// # States
 /// * `Locked` - Funds are held in escrow, awaiting release or refund
 /// * `Released` - Funds have been transferred to contributor (final state)
 /// * `Refunded` - Funds have been returned to depositor (final state)
+/// * `Cancelled` - Depositor withdrew the bounty with admin co-signature (final state)
+/// * `Disputed` - Depositor objected to a pending release; awaiting admin resolution
+/// * `Streaming` - Funds are being paid out linearly to a contributor
 ///
 /// # Invariants
 /// - Once in Released or Refunded state, no further transitions allowed
@@ -502,6 +577,9 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    Disputed,
+    Cancelled,
+    Streaming,
 }
 
 #[contracttype]
@@ -535,10 +613,15 @@ pub struct RefundApproval {
 /// Complete escrow record for a bounty.
 ///
 /// # Fields
-/// * `depositor` - Address that locked the funds (receives refunds)
+/// * `depositor` - Address that owns the escrow (receives refunds unless a
+///   refund address is set with `set_refund_address`)
 /// * `amount` - Token amount held in escrow (in smallest denomination)
 /// * `status` - Current state of the escrow (Locked/Released/Refunded)
 /// * `deadline` - Unix timestamp after which refunds are allowed
+/// * `token` - Token contract the escrow is denominated in
+/// * `initiator` - Address that created the lock; differs from `depositor`
+///   when an operator funded the bounty from the depositor's allowance
+/// * `frozen` - Set by `freeze_bounty`; blocks release, refund and claim
 ///
 /// # Storage
 /// Stored in persistent storage with key `DataKey::Escrow(bounty_id)`.
@@ -551,6 +634,9 @@ pub struct RefundApproval {
 ///     amount: 1000_0000000, // 1000 tokens
 ///     status: EscrowStatus::Locked,
 ///     deadline: current_time + 2592000, // 30 days
+///     token: usdc_address,
+///     initiator: depositor_address,
+///     frozen: false,
 /// };
 /// ```
 #[contracttype]
@@ -562,13 +648,40 @@ pub struct Escrow {
     pub deadline: u64,
     pub refund_history: Vec<RefundRecord>,
     pub remaining_amount: i128,
+    pub token: Address,
+    pub initiator: Address,
+    pub frozen: bool,
+}
+
+/// Escrow record as reported by `get_escrow_info`.
+///
+/// Carries every field of the stored `Escrow` plus values computed at query
+/// time, which are never persisted.
+///
+/// # Fields
+/// * `payable_amount` - Amount a release would pay right now before fees:
+///   `amount` plus the boost accrued so far for a Locked or Disputed escrow,
+///   zero once the escrow can no longer be released
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInfo {
+    pub depositor: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+    pub refund_history: Vec<RefundRecord>,
+    pub remaining_amount: i128,
+    pub token: Address,
+    pub initiator: Address,
+    pub frozen: bool,
+    pub payable_amount: i128,
 }
 
 /// Storage keys for contract data.
 ///
 /// # Keys
 /// * `Admin` - Stores the admin address (instance storage)
-/// * `Token` - Stores the token contract address (instance storage)
+/// * `Token` - Stores the default token contract address (instance storage)
 /// * `Escrow(u64)` - Stores escrow data indexed by bounty_id (persistent storage)
 ///
 /// # Storage Types
@@ -581,6 +694,7 @@ pub struct LockFundsItem {
     pub depositor: Address,
     pub amount: i128,
     pub deadline: u64,
+    pub token: Address,
 }
 
 #[contracttype]
@@ -598,8 +712,84 @@ const MAX_BATCH_SIZE: u32 = 100;
 pub struct FeeConfig {
     pub lock_fee_rate: i128, // Fee rate for lock operations (basis points, e.g., 100 = 1%)
     pub release_fee_rate: i128, // Fee rate for release operations (basis points)
-    pub fee_recipient: Address, // Address to receive fees
     pub fee_enabled: bool,   // Global fee enable/disable flag
+    pub cancel_fee_rate: i128, // Fee rate for depositor cancellations (basis points)
+    pub fee_splits: Vec<FeeSplit>, // Weighted recipients; empty = accrue to the fee ledger
+}
+
+/// Preview of a fee-bearing operation under the current fee configuration.
+///
+/// # Fields
+/// * `gross` - Amount the fee is charged on
+/// * `fee` - Fee that would be charged
+/// * `net` - `gross - fee`
+/// * `recipients` - Where the fee would go: each fee split recipient's share,
+///   or a single share for the contract's fee ledger (the contract address)
+///   when no split is set, plus the referrer's share if any. Empty when no
+///   fee is charged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeQuote {
+    pub gross: i128,
+    pub fee: i128,
+    pub net: i128,
+    pub recipients: Vec<events::FeeShare>,
+}
+
+/// One recipient's weight in a fee split, in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSplit {
+    pub recipient: Address,
+    pub weight_bps: u32,
+}
+
+// Maximum number of recipients in a fee split
+const MAX_FEE_SPLITS: u32 = 10;
+
+/// Prize table for a competitive bounty, fixed at lock time.
+///
+/// `prize_shares[i]` is the share of the escrowed amount paid to the entrant
+/// ranked `i + 1`, in basis points. Shares may sum to less than 10,000; the
+/// unawarded remainder is refunded to the depositor when the contest is
+/// finalized. `judge`, if set, may finalize the contest alongside the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContestConfig {
+    pub prize_shares: Vec<u32>,
+    pub judge: Option<Address>,
+}
+
+/// Running totals kept for each accepted token.
+///
+/// # Fields
+/// * `locked` - Amount currently owed to escrows in this token; the contract
+///   is solvent for the token while its balance is at least this amount
+/// * `fees_collected` - Cumulative fees collected in this token (withdrawn
+///   or not; see `get_accrued_fees` for the unwithdrawn part)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenTotals {
+    pub locked: i128,
+    pub fees_collected: i128,
+}
+
+/// Bounds enforced on every new escrow.
+///
+/// # Fields
+/// * `min_deadline_horizon` / `max_deadline_horizon` - Allowed distance, in
+///   seconds, between the current ledger time and the escrow deadline
+/// * `min_amount` / `max_amount` - Allowed gross lock amount
+/// * `max_locked_per_depositor` - Optional cap on the amount a single
+///   depositor may have locked at once, per token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockPolicy {
+    pub min_deadline_horizon: u64,
+    pub max_deadline_horizon: u64,
+    pub min_amount: i128,
+    pub max_amount: i128,
+    pub max_locked_per_depositor: Option<i128>,
 }
 
 // Fee rate is stored in basis points (1 basis point = 0.01%)
@@ -607,6 +797,171 @@ pub struct FeeConfig {
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+/// Time after a contest's deadline during which only `finalize_contest` can
+/// pay it out; refunds and cancellations open once it has passed, so a
+/// contest whose judge never acts does not lock the prize pool forever.
+const CONTEST_JUDGING_WINDOW: u64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Current contract version, stored at initialization.
+///
+/// There is no `migrate` yet: add one together with the first version whose
+/// storage layout differs, instead of bumping this alone.
+///
+/// # Version History
+/// - v1: Initial upgradeable release
+const VERSION: u32 = 1;
+
+/// Configuration for delayed (challengeable) releases.
+///
+/// Escrows whose amount is at or above `amount_threshold` must be released
+/// through `initiate_release`/`finalize_release`, giving the depositor
+/// `challenge_period` seconds to object. A `challenge_period` of zero
+/// disables the threshold rule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeConfig {
+    pub challenge_period: u64,
+    pub amount_threshold: i128,
+}
+
+/// Lifecycle of an application stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakeStatus {
+    Active,
+    Returned,
+    Slashed,
+}
+
+/// Where a slashed application stake goes: the bounty's depositor, or the
+/// contract's fee ledger (withdrawn with `withdraw_fees`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SlashTarget {
+    Depositor,
+    Treasury,
+}
+
+/// Deposit an applicant locked to apply to a bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationStake {
+    pub amount: i128,
+    pub token: Address,
+    pub status: StakeStatus,
+    pub staked_at: u64,
+}
+
+/// Permanent record of a contributor's payout from a bounty. Receipts are
+/// keyed by (contributor, bounty) and cannot be transferred.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionReceipt {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    /// Net amount paid, summed over every payout from the bounty
+    pub amount: i128,
+    pub token: Address,
+    /// Time of the latest payout
+    pub timestamp: u64,
+    /// Bounty metadata hash at the time of the latest payout; all zeros
+    /// when the bounty has none
+    pub metadata_hash: BytesN<32>,
+}
+
+/// Running totals for a contributor in one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorStats {
+    pub token: Address,
+    /// Net amount received
+    pub earned: i128,
+    /// Release fees deducted from payouts
+    pub fees_paid: i128,
+    pub payouts: u32,
+}
+
+/// Running totals for a depositor in one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositorStats {
+    pub token: Address,
+    /// Gross amount locked, including lock fees and boost reserves
+    pub funded: i128,
+    /// Amount returned to the depositor's refund address
+    pub refunded: i128,
+    /// Lock and cancellation fees charged
+    pub fees_paid: i128,
+}
+
+/// How a bounty's boost grows.
+///
+/// * `Linear` - Grows continuously by `rate_per_day`
+/// * `Step` - Grows by `rate_per_day` at the end of each full day
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BoostCurve {
+    Linear,
+    Step,
+}
+
+/// Boost attached to a bounty with `lock_boosted_funds`.
+///
+/// The boost grows from `start_time` along `curve` and is capped at
+/// `reserve`, which the depositor funds up front.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoostConfig {
+    pub reserve: i128,
+    pub rate_per_day: i128,
+    pub curve: BoostCurve,
+    pub start_time: u64,
+}
+
+/// KYC attestation recorded by the trusted attester for an address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub level: u32,
+    pub expires_at: u64,
+    pub attester: Address,
+    pub recorded_at: u64,
+}
+
+/// Release policy requiring an attestation of at least `min_level` for
+/// payouts of bounties whose escrowed amount exceeds `threshold`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KycPolicy {
+    pub min_level: u32,
+    pub threshold: i128,
+}
+
+/// A linear payout of an escrow to a contributor.
+///
+/// `total` accrues evenly between `start_time` and `end_time`; the
+/// contributor withdraws the accrued part with `withdraw_stream`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub contributor: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub total: i128,
+    pub withdrawn: i128,
+}
+
+/// A release initiated by the admin that becomes final once the
+/// challenge window has elapsed without an objection.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRelease {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub initiated_at: u64,
+    pub finalize_after: u64,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -615,7 +970,41 @@ pub enum DataKey {
     FeeConfig,           // Fee configuration
     RefundApproval(u64), // bounty_id -> RefundApproval
     ReentrancyGuard,
-    IsPaused, // Contract pause state
+    Version,                            // Current contract version
+    PreviousVersion,                    // Version before the last upgrade
+    IsPaused,                           // Contract pause state
+    ChallengeConfig,                    // Delayed-release configuration
+    ChallengePeriod(u64),               // bounty_id -> challenge window override (seconds)
+    PendingRelease(u64),                // bounty_id -> PendingRelease
+    RefundTo(u64),                      // bounty_id -> refund address override
+    Contest(u64),                       // bounty_id -> ContestConfig
+    Submissions(u64),                   // bounty_id -> Vec<Address> of contest entrants
+    AcceptedTokens,                     // Vec<Address> of tokens allowed for new escrows
+    TokenTotals(Address),               // token -> TokenTotals
+    LockOperator(Address),              // operator allowed to call lock_funds_from
+    LockPolicy,                         // Deadline and amount bounds for new escrows
+    DepositorLocked(Address, Address),  // (depositor, token) -> amount currently locked
+    FreezeReason(u64),                  // bounty_id -> reason code of an active freeze
+    Denylisted(Address),                // Address blocked from sending or receiving funds
+    AccruedFees(Address),               // token -> fees held by the contract, not yet withdrawn
+    FeeBalance(Address, Address),       // (recipient, token) -> claimable fee share
+    FeeClaimants(Address),              // token -> Vec<Address> with a non-zero FeeBalance
+    Referrer(u64),                      // bounty_id -> referrer credited with fee share
+    ReferralShare,                      // Share of lock/release fees paid to referrers (bps)
+    ReferralEarnings(Address, Address), // (referrer, token) -> cumulative referral rewards
+    Stream(u64),                        // bounty_id -> Stream
+    Attester,                           // Trusted KYC attester
+    Attestation(Address),               // address -> Attestation
+    KycPolicy,                          // KycPolicy for releases
+    Boost(u64),                         // bounty_id -> BoostConfig
+    StakeRequirement(u64),              // bounty_id -> stake required to apply
+    Stake(u64, Address),                // (bounty_id, applicant) -> ApplicationStake
+    Applicants(u64),                    // bounty_id -> Vec<Address> that have staked
+    MetadataHash(u64),                  // bounty_id -> BytesN<32> hash of off-chain metadata
+    Receipt(Address, u64),              // (contributor, bounty_id) -> CompletionReceipt
+    ContributorReceipts(Address),       // contributor -> Vec<u64> bounty ids, in payout order
+    ContributorStats(Address),          // contributor -> Vec<ContributorStats>, one per token
+    DepositorStats(Address),            // depositor -> Vec<DepositorStats>, one per token
 }
 
 // ============================================================================
@@ -644,7 +1033,7 @@ impl BountyEscrowContract {
     ///
     /// # State Changes
     /// - Sets Admin address in instance storage
-    /// - Sets Token address in instance storage
+    /// - Sets Token address in instance storage and adds it to the token allowlist
     /// - Emits BountyEscrowInitialized event
     ///
     /// # Security Considerations
@@ -681,17 +1070,22 @@ impl BountyEscrowContract {
         // Store configuration
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&DataKey::AcceptedTokens, &vec![&env, token.clone()]);
 
         // Initialize fee config with zero fees (disabled by default)
         let fee_config = FeeConfig {
             lock_fee_rate: 0,
             release_fee_rate: 0,
-            fee_recipient: admin.clone(),
             fee_enabled: false,
+            cancel_fee_rate: 0,
+            fee_splits: vec![&env],
         };
         env.storage()
             .instance()
             .set(&DataKey::FeeConfig, &fee_config);
+        env.storage().instance().set(&DataKey::Version, &VERSION);
 
         // Emit initialization event
         emit_bounty_initialized(
@@ -713,6 +1107,67 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    // ========================================================================
+    // Upgrades
+    // ========================================================================
+
+    /// Upgrades the contract to new WASM code (admin only).
+    ///
+    /// Escrows and configuration live in contract storage and survive the
+    /// upgrade. New code must keep reading the current storage layout until
+    /// a migration entry point exists.
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - Hash of the uploaded WASM code
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let current_version = Self::get_version(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::PreviousVersion, &current_version);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::emit_contract_upgraded(
+            &env,
+            events::ContractUpgraded {
+                version: current_version,
+                new_wasm_hash,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("upgrade"), admin, true);
+        Ok(())
+    }
+
+    /// Get the current contract version (view function).
+    /// Contracts initialized before versioning report version 1.
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(1)
+    }
+
+    /// Get the version recorded before the last upgrade (view function)
+    pub fn get_previous_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::PreviousVersion)
+    }
+
+    // ========================================================================
+    // Event Envelope
+    // ========================================================================
+
+    /// Get the sequence number of the last event emitted (view function)
+    pub fn get_event_sequence(env: Env) -> u64 {
+        events::get_sequence(&env)
+    }
+
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         if fee_rate == 0 {
@@ -734,18 +1189,22 @@ impl BountyEscrowContract {
             .unwrap_or_else(|| FeeConfig {
                 lock_fee_rate: 0,
                 release_fee_rate: 0,
-                fee_recipient: env.storage().instance().get(&DataKey::Admin).unwrap(),
                 fee_enabled: false,
+                cancel_fee_rate: 0,
+                fee_splits: vec![env],
             })
     }
 
     /// Update fee configuration (admin only)
+    ///
+    /// Fees are booked to the fee splits, or to the contract's fee ledger
+    /// when no split is set (see `set_fee_splits` and `withdraw_fees`).
     pub fn update_fee_config(
         env: Env,
         lock_fee_rate: Option<i128>,
         release_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
+        cancel_fee_rate: Option<i128>,
     ) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
@@ -770,8 +1229,11 @@ impl BountyEscrowContract {
             fee_config.release_fee_rate = rate;
         }
 
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
+        if let Some(rate) = cancel_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+            fee_config.cancel_fee_rate = rate;
         }
 
         if let Some(enabled) = fee_enabled {
@@ -787,8 +1249,8 @@ impl BountyEscrowContract {
             events::FeeConfigUpdated {
                 lock_fee_rate: fee_config.lock_fee_rate,
                 release_fee_rate: fee_config.release_fee_rate,
-                fee_recipient: fee_config.fee_recipient.clone(),
                 fee_enabled: fee_config.fee_enabled,
+                cancel_fee_rate: fee_config.cancel_fee_rate,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -801,26 +1263,120 @@ impl BountyEscrowContract {
         Self::get_fee_config_internal(&env)
     }
 
+    /// Builds a quote for `gross` at `fee_rate` when fees are enabled,
+    /// including the recipients the fee would be booked to (internal helper)
+    fn quote_fee(
+        env: &Env,
+        fee_config: &FeeConfig,
+        gross: i128,
+        fee_rate: i128,
+        referrer: Option<Address>,
+    ) -> FeeQuote {
+        let fee = if fee_config.fee_enabled && fee_rate > 0 {
+            Self::calculate_fee(gross, fee_rate)
+        } else {
+            0
+        };
+        let referral_amount = match &referrer {
+            Some(_) => Self::calculate_fee(fee, Self::get_referral_share(env.clone()) as i128),
+            None => 0,
+        };
+
+        let mut recipients = Self::split_fee(env, fee_config, fee - referral_amount);
+        if let (Some(referrer), true) = (referrer, referral_amount > 0) {
+            recipients.push_back(events::FeeShare {
+                recipient: referrer,
+                amount: referral_amount,
+            });
+        }
+        FeeQuote {
+            gross,
+            fee,
+            net: gross - fee,
+            recipients,
+        }
+    }
+
+    /// Previews the lock fee `depositor` would pay to lock `amount` (view function).
+    ///
+    /// `net` is the amount that would be recorded as the escrow's `amount`.
+    /// `referrer` is the referrer that would be passed to `lock_funds`.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is outside the lock policy bounds
+    /// * `AddressDenylisted` - `depositor` is on the denylist
+    /// * `InvalidReferrer` - `depositor` names itself as referrer
+    pub fn quote_lock(
+        env: Env,
+        amount: i128,
+        depositor: Address,
+        referrer: Option<Address>,
+    ) -> Result<FeeQuote, Error> {
+        Self::check_amount_policy(&env, amount)?;
+        Self::ensure_not_denylisted(&env, &depositor)?;
+        if referrer == Some(depositor) {
+            return Err(Error::InvalidReferrer);
+        }
+
+        let fee_config = Self::get_fee_config_internal(&env);
+        Ok(Self::quote_fee(
+            &env,
+            &fee_config,
+            amount,
+            fee_config.lock_fee_rate,
+            referrer,
+        ))
+    }
+
+    /// Previews the release fee and contributor payout for a bounty (view function).
+    ///
+    /// `gross` includes the boost a time-boosted bounty has accrued so far.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Escrow has already been paid out or refunded
+    pub fn quote_release(env: Env, bounty_id: u64) -> Result<FeeQuote, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::Disputed {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let fee_config = Self::get_fee_config_internal(&env);
+        Ok(Self::quote_fee(
+            &env,
+            &fee_config,
+            Self::payable_amount_internal(&env, bounty_id, &escrow),
+            fee_config.release_fee_rate,
+            env.storage()
+                .persistent()
+                .get(&DataKey::Referrer(bounty_id)),
+        ))
+    }
+
     // ========================================================================
-    // Pause and Emergency Functions
+    // Accepted Tokens
     // ========================================================================
 
-    /// Check if contract is paused (internal helper)
-    fn is_paused_internal(env: &Env) -> bool {
+    /// Check whether a token is on the allowlist (internal helper)
+    fn is_token_accepted_internal(env: &Env, token: &Address) -> bool {
         env.storage()
-            .persistent()
-            .get::<_, bool>(&DataKey::IsPaused)
+            .instance()
+            .get::<_, Vec<Address>>(&DataKey::AcceptedTokens)
+            .map(|tokens| tokens.contains(token))
             .unwrap_or(false)
     }
 
-    /// Get pause status (view function)
-    pub fn is_paused(env: Env) -> bool {
-        Self::is_paused_internal(&env)
-    }
-
-    /// Pause the contract (admin only)
-    /// Prevents new fund locks, releases, and refunds
-    pub fn pause(env: Env) -> Result<(), Error> {
+    /// Add or remove a token from the allowlist of tokens accepted for new
+    /// escrows (admin only).
+    ///
+    /// Removing a token only blocks new locks; existing escrows in that token
+    /// can still be released and refunded.
+    pub fn set_token_accepted(env: Env, token: Address, accepted: bool) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
@@ -828,16 +1384,30 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        if Self::is_paused_internal(&env) {
-            return Ok(()); // Already paused, idempotent
+        let mut tokens: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::AcceptedTokens)
+            .unwrap_or(vec![&env]);
+        let index = tokens.first_index_of(&token);
+
+        match (accepted, index) {
+            (true, None) => tokens.push_back(token.clone()),
+            (false, Some(i)) => {
+                tokens.remove(i);
+            }
+            _ => return Ok(()), // Already in the requested state, idempotent
         }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::AcceptedTokens, &tokens);
 
-        emit_contract_paused(
+        events::emit_token_allowlist_updated(
             &env,
-            ContractPaused {
-                paused_by: admin.clone(),
+            events::TokenAllowlistUpdated {
+                token,
+                accepted,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -845,9 +1415,39 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Unpause the contract (admin only)
-    /// Resumes normal operations
-    pub fn unpause(env: Env) -> Result<(), Error> {
+    /// Check whether a token is accepted for new escrows (view function)
+    pub fn is_token_accepted(env: Env, token: Address) -> bool {
+        Self::is_token_accepted_internal(&env, &token)
+    }
+
+    /// List all tokens accepted for new escrows (view function)
+    pub fn get_accepted_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AcceptedTokens)
+            .unwrap_or(vec![&env])
+    }
+
+    // ========================================================================
+    // Fee Ledger
+    // ========================================================================
+
+    /// Credits a collected fee to the contract's fee ledger (internal helper)
+    fn accrue_fee(env: &Env, token: &Address, amount: i128) {
+        let key = DataKey::AccruedFees(token.clone());
+        let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(accrued + amount));
+    }
+
+    /// Withdraws accrued fees in `token` (admin only).
+    ///
+    /// Lock, release and cancel fees are kept in the contract instead of being
+    /// transferred on every operation; this sweeps them out in one transfer.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - `amount` is not positive
+    /// * `InsufficientAccruedFees` - `amount` exceeds the accrued fees
+    pub fn withdraw_fees(env: Env, token: Address, amount: i128, to: Address) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
@@ -855,16 +1455,28 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        if !Self::is_paused_internal(&env) {
-            return Ok(()); // Already unpaused, idempotent
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &false);
+        let key = DataKey::AccruedFees(token.clone());
+        let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount > accrued {
+            return Err(Error::InsufficientAccruedFees);
+        }
+        env.storage().persistent().set(&key, &(accrued - amount));
 
-        emit_contract_unpaused(
+        let client = token::Client::new(&env, &token);
+        client.transfer(&env.current_contract_address(), &to, &amount);
+
+        events::emit_fees_withdrawn(
             &env,
-            ContractUnpaused {
-                unpaused_by: admin.clone(),
+            events::FeesWithdrawn {
+                token,
+                amount,
+                to,
+                withdrawn_by: admin,
+                remaining: accrued - amount,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -872,611 +1484,3896 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Emergency withdrawal for all contract funds (admin only, only when paused)
-    /// This function allows admins to recover all contract funds in case of critical
-    /// security issues or unrecoverable bugs. It can only be called when the contract
-    /// is paused to prevent misuse.
-    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
+    /// Returns the fees in `token` held by the contract and not yet withdrawn (view function)
+    pub fn get_accrued_fees(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccruedFees(token))
+            .unwrap_or(0)
+    }
+
+    /// Divides a fee between the fee split recipients by weight, rounding
+    /// dust going to the first recipient; without a split the whole fee is
+    /// a single share for the contract's fee ledger (internal helper).
+    /// Returns no shares for a zero fee.
+    fn split_fee(env: &Env, fee_config: &FeeConfig, amount: i128) -> Vec<events::FeeShare> {
+        let mut shares: Vec<events::FeeShare> = vec![env];
+        if amount <= 0 {
+            return shares;
+        }
+        if fee_config.fee_splits.is_empty() {
+            shares.push_back(events::FeeShare {
+                recipient: env.current_contract_address(),
+                amount,
+            });
+            return shares;
+        }
+
+        let mut distributed: i128 = 0;
+        for split in fee_config.fee_splits.iter() {
+            let share = Self::calculate_fee(amount, split.weight_bps as i128);
+            distributed += share;
+            shares.push_back(events::FeeShare {
+                recipient: split.recipient,
+                amount: share,
+            });
+        }
+        let mut first = shares.get(0).unwrap();
+        first.amount += amount - distributed;
+        shares.set(0, first);
+        shares
+    }
+
+    /// Books a collected fee (internal helper).
+    ///
+    /// Without a fee split the fee goes to the contract's fee ledger. With a
+    /// split, each recipient's weighted share (see `split_fee`) is credited
+    /// to its claimable balance. Returns the shares credited.
+    fn collect_fee(
+        env: &Env,
+        fee_config: &FeeConfig,
+        token: &Address,
+        amount: i128,
+    ) -> Vec<events::FeeShare> {
+        if fee_config.fee_splits.is_empty() {
+            Self::accrue_fee(env, token, amount);
+            return vec![env];
+        }
+
+        let shares = Self::split_fee(env, fee_config, amount);
+        for share in shares.iter() {
+            Self::credit_fee_balance(env, &share.recipient, token, share.amount);
+        }
+        shares
+    }
+
+    /// Adds to a recipient's claimable fee balance (internal helper)
+    fn credit_fee_balance(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+        let key = DataKey::FeeBalance(recipient.clone(), token.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+
+        if balance == 0 {
+            let claimants_key = DataKey::FeeClaimants(token.clone());
+            let mut claimants: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&claimants_key)
+                .unwrap_or(vec![env]);
+            claimants.push_back(recipient.clone());
+            env.storage().persistent().set(&claimants_key, &claimants);
+        }
+    }
+
+    /// Drops `recipient` from the token's fee claimant index (internal helper)
+    fn remove_fee_claimant(env: &Env, recipient: &Address, token: &Address) {
+        let claimants_key = DataKey::FeeClaimants(token.clone());
+        let mut claimants: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&claimants_key)
+            .unwrap_or(vec![env]);
+        if let Some(index) = claimants.first_index_of(recipient) {
+            claimants.remove(index);
+            env.storage().persistent().set(&claimants_key, &claimants);
+        }
+    }
+
+    /// Sets the weighted list of fee recipients (admin only).
+    ///
+    /// Weights are in basis points and must sum to 10,000. Pass an empty list
+    /// to go back to accruing fees in the contract's fee ledger.
+    ///
+    /// # Errors
+    /// * `InvalidFeeSplit` - Too many recipients, a zero weight, or weights
+    ///   that do not sum to 10,000
+    pub fn set_fee_splits(env: Env, splits: Vec<FeeSplit>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if splits.len() > MAX_FEE_SPLITS {
+            return Err(Error::InvalidFeeSplit);
+        }
+        if !splits.is_empty() {
+            let mut total: u32 = 0;
+            for split in splits.iter() {
+                if split.weight_bps == 0 {
+                    return Err(Error::InvalidFeeSplit);
+                }
+                total = total.saturating_add(split.weight_bps);
+            }
+            if total as i128 != BASIS_POINTS {
+                return Err(Error::InvalidFeeSplit);
+            }
+        }
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+        fee_config.fee_splits = splits.clone();
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeConfig, &fee_config);
+
+        events::emit_fee_splits_updated(
+            &env,
+            events::FeeSplitsUpdated {
+                splits,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Pays out a recipient's claimable fee share in `token`.
+    ///
+    /// # Returns
+    /// The amount transferred (zero if nothing was owed).
+    pub fn claim_fees(env: Env, recipient: Address, token: Address) -> Result<i128, Error> {
+        recipient.require_auth();
+
+        let key = DataKey::FeeBalance(recipient.clone(), token.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount == 0 {
+            return Ok(0);
+        }
+        env.storage().persistent().remove(&key);
+        Self::remove_fee_claimant(&env, &recipient, &token);
+
+        let client = token::Client::new(&env, &token);
+        client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        events::emit_fees_claimed(
+            &env,
+            events::FeesClaimed {
+                recipient,
+                token,
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(amount)
+    }
+
+    /// Returns a recipient's claimable fee share in `token` (view function)
+    pub fn get_claimable_fees(env: Env, recipient: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeBalance(recipient, token))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Referrals
+    // ========================================================================
+
+    /// Books a lock or release fee, first crediting the bounty's referrer
+    /// with the referral share (internal helper).
+    ///
+    /// The remainder is booked with `collect_fee`. Returns every share
+    /// credited to an address, including the referrer's.
+    fn collect_fee_with_referral(
+        env: &Env,
+        bounty_id: u64,
+        fee_config: &FeeConfig,
+        token: &Address,
+        fee_amount: i128,
+        operation_type: events::FeeOperationType,
+    ) -> Vec<events::FeeShare> {
+        let referrer: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Referrer(bounty_id));
+        let referral_amount = match &referrer {
+            Some(_) => {
+                Self::calculate_fee(fee_amount, Self::get_referral_share(env.clone()) as i128)
+            }
+            None => 0,
+        };
+
+        let mut shares = Self::collect_fee(env, fee_config, token, fee_amount - referral_amount);
+        if let (Some(referrer), true) = (referrer, referral_amount > 0) {
+            Self::credit_fee_balance(env, &referrer, token, referral_amount);
+
+            let key = DataKey::ReferralEarnings(referrer.clone(), token.clone());
+            let earned: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&key, &(earned + referral_amount));
+
+            events::emit_referral_reward(
+                env,
+                events::ReferralReward {
+                    bounty_id,
+                    referrer: referrer.clone(),
+                    token: token.clone(),
+                    amount: referral_amount,
+                    operation_type,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+            shares.push_back(events::FeeShare {
+                recipient: referrer,
+                amount: referral_amount,
+            });
+        }
+        shares
+    }
+
+    /// Sets the share of lock and release fees paid to referrers, in basis
+    /// points (admin only).
+    ///
+    /// Referral rewards are credited to the referrer's claimable fee balance
+    /// and withdrawn with `claim_fees`.
+    pub fn set_referral_share(env: Env, share_bps: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if share_bps as i128 > BASIS_POINTS {
+            return Err(Error::InvalidFeeRate);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReferralShare, &share_bps);
+
+        Ok(())
+    }
+
+    /// Get the referral share of lock and release fees (view function)
+    pub fn get_referral_share(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReferralShare)
+            .unwrap_or(0)
+    }
+
+    /// Get the referrer recorded for a bounty, if any (view function)
+    pub fn get_bounty_referrer(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Referrer(bounty_id))
+    }
+
+    /// Get the total referral rewards a referrer has earned in `token`,
+    /// claimed or not (view function)
+    pub fn get_referral_earnings(env: Env, referrer: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralEarnings(referrer, token))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Denylist
+    // ========================================================================
+
+    /// Fails with `AddressDenylisted` if `address` is on the denylist (internal helper)
+    fn ensure_not_denylisted(env: &Env, address: &Address) -> Result<(), Error> {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Denylisted(address.clone()))
+        {
+            return Err(Error::AddressDenylisted);
+        }
+        Ok(())
+    }
+
+    /// Adds or removes an address from the denylist (admin only).
+    ///
+    /// Denylisted addresses cannot fund escrows, receive releases or receive
+    /// refunds. This is independent of the anti-abuse whitelist, which only
+    /// exempts addresses from rate limiting.
+    pub fn set_denylisted(env: Env, address: Address, denylisted: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let key = DataKey::Denylisted(address.clone());
+        if denylisted {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::emit_denylist_updated(
+            &env,
+            events::DenylistUpdated {
+                address,
+                denylisted,
+                updated_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Checks whether an address is on the denylist (view function)
+    pub fn is_denylisted(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Denylisted(address))
+    }
+
+    // ========================================================================
+    // KYC Attestations
+    // ========================================================================
+
+    /// Fails with `AttestationRequired` if the KYC policy applies to a payout
+    /// of a bounty holding `amount` and `address` lacks a valid attestation
+    /// (internal helper)
+    fn ensure_attested(env: &Env, address: &Address, amount: i128) -> Result<(), Error> {
+        let policy: KycPolicy = match env.storage().instance().get(&DataKey::KycPolicy) {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+        if amount <= policy.threshold {
+            return Ok(());
+        }
+
+        let attestation: Option<Attestation> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Attestation(address.clone()));
+        match attestation {
+            Some(a) if a.level >= policy.min_level && a.expires_at > env.ledger().timestamp() => {
+                Ok(())
+            }
+            _ => Err(Error::AttestationRequired),
+        }
+    }
+
+    /// Sets the trusted KYC attester (admin only)
+    pub fn set_attester(env: Env, attester: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Attester, &attester);
+
+        events::emit_attester_updated(
+            &env,
+            events::AttesterUpdated {
+                attester,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the trusted KYC attester, if set (view function)
+    pub fn get_attester(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Attester)
+    }
+
+    /// Records or replaces the KYC attestation for `subject` (attester only).
+    ///
+    /// # Errors
+    /// * `Unauthorized` - No attester has been set
+    /// * `InvalidDeadline` - `expires_at` is not in the future
+    pub fn record_attestation(
+        env: Env,
+        subject: Address,
+        level: u32,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        let attester: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Attester)
+            .ok_or(Error::Unauthorized)?;
+        attester.require_auth();
+
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(Error::InvalidDeadline);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::Attestation(subject.clone()),
+            &Attestation {
+                level,
+                expires_at,
+                attester: attester.clone(),
+                recorded_at: now,
+            },
+        );
+
+        events::emit_attestation_recorded(
+            &env,
+            events::AttestationRecorded {
+                subject,
+                level,
+                expires_at,
+                attester,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Removes the KYC attestation for `subject` (attester only)
+    pub fn revoke_attestation(env: Env, subject: Address) -> Result<(), Error> {
+        let attester: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Attester)
+            .ok_or(Error::Unauthorized)?;
+        attester.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Attestation(subject.clone()));
+
+        events::emit_attestation_revoked(
+            &env,
+            events::AttestationRevoked {
+                subject,
+                attester,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the KYC attestation recorded for an address, expired or not (view function)
+    pub fn get_attestation(env: Env, subject: Address) -> Option<Attestation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attestation(subject))
+    }
+
+    /// Sets or clears the KYC policy for releases (admin only).
+    ///
+    /// When set, `release_funds`, `batch_release_funds`, `finalize_release`
+    /// and `withdraw_stream` fail with `AttestationRequired` for bounties
+    /// above `threshold` unless the contributor holds an unexpired
+    /// attestation of at least `min_level`.
+    pub fn set_kyc_policy(env: Env, policy: Option<KycPolicy>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        match &policy {
+            Some(policy) => {
+                if policy.threshold < 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                env.storage().instance().set(&DataKey::KycPolicy, policy);
+            }
+            None => env.storage().instance().remove(&DataKey::KycPolicy),
+        }
+
+        events::emit_kyc_policy_updated(
+            &env,
+            events::KycPolicyUpdated {
+                enabled: policy.is_some(),
+                min_level: policy.as_ref().map_or(0, |p| p.min_level),
+                threshold: policy.as_ref().map_or(0, |p| p.threshold),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the KYC policy for releases, if any (view function)
+    pub fn get_kyc_policy(env: Env) -> Option<KycPolicy> {
+        env.storage().instance().get(&DataKey::KycPolicy)
+    }
+
+    // ========================================================================
+    // Compliance Freeze
+    // ========================================================================
+
+    /// Freezes a single bounty (admin only).
+    ///
+    /// Unlike `pause`, this only affects one escrow: release, refund,
+    /// cancellation and claim paths (`finalize_release`, `finalize_contest`)
+    /// fail with `BountyFrozen` until `unfreeze_bounty` is called.
+    ///
+    /// # Arguments
+    /// * `bounty_id` - Bounty to freeze
+    /// * `reason_code` - Application-defined code recorded in events
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `BountyFrozen` - Bounty is already frozen
+    pub fn freeze_bounty(env: Env, bounty_id: u64, reason_code: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+
+        escrow.frozen = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::FreezeReason(bounty_id), &reason_code);
+
+        events::emit_bounty_frozen(
+            &env,
+            events::BountyFrozen {
+                bounty_id,
+                reason_code,
+                frozen_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Lifts a freeze placed with `freeze_bounty` (admin only).
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `BountyNotFrozen` - Bounty is not frozen
+    pub fn unfreeze_bounty(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if !escrow.frozen {
+            return Err(Error::BountyNotFrozen);
+        }
+
+        let reason_code: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FreezeReason(bounty_id))
+            .unwrap_or(0);
+
+        escrow.frozen = false;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FreezeReason(bounty_id));
+
+        events::emit_bounty_unfrozen(
+            &env,
+            events::BountyUnfrozen {
+                bounty_id,
+                reason_code,
+                unfrozen_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the reason code of an active freeze, if any (view function).
+    pub fn get_freeze_reason(env: Env, bounty_id: u64) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FreezeReason(bounty_id))
+    }
+
+    // ========================================================================
+    // Lock Policy
+    // ========================================================================
+
+    /// Get the lock policy (internal helper)
+    fn get_lock_policy_internal(env: &Env) -> LockPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::LockPolicy)
+            .unwrap_or(LockPolicy {
+                min_deadline_horizon: 1,
+                max_deadline_horizon: u64::MAX,
+                min_amount: 1,
+                max_amount: i128::MAX,
+                max_locked_per_depositor: None,
+            })
+    }
+
+    /// Check a gross lock amount against the policy bounds (internal helper)
+    fn check_amount_policy(env: &Env, amount: i128) -> Result<(), Error> {
+        let policy = Self::get_lock_policy_internal(env);
+        if amount <= 0 || amount < policy.min_amount || amount > policy.max_amount {
+            return Err(Error::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    /// Check a deadline against the policy horizon (internal helper)
+    fn check_deadline_policy(env: &Env, deadline: u64) -> Result<(), Error> {
+        let policy = Self::get_lock_policy_internal(env);
+        let now = env.ledger().timestamp();
+        if deadline <= now
+            || deadline < now.saturating_add(policy.min_deadline_horizon)
+            || deadline > now.saturating_add(policy.max_deadline_horizon)
+        {
+            return Err(Error::InvalidDeadline);
+        }
+        Ok(())
+    }
+
+    /// Check that locking `amount` keeps the depositor within its cap (internal helper)
+    fn check_depositor_cap(
+        env: &Env,
+        depositor: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if let Some(cap) = Self::get_lock_policy_internal(env).max_locked_per_depositor {
+            let locked = Self::get_depositor_locked(env.clone(), depositor.clone(), token.clone());
+            if locked.saturating_add(amount) > cap {
+                return Err(Error::DepositorCapExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a new escrow against every lock policy rule (internal helper).
+    ///
+    /// All lock paths (single, batch, operator, contest) go through this so
+    /// that a policy change applies everywhere at once.
+    fn check_lock_policy(
+        env: &Env,
+        depositor: &Address,
+        token: &Address,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::check_amount_policy(env, amount)?;
+        Self::check_deadline_policy(env, deadline)?;
+        Self::check_depositor_cap(env, depositor, token, amount)
+    }
+
+    /// Adjusts the amount a depositor has locked in a token (internal helper)
+    fn adjust_depositor_locked(env: &Env, depositor: &Address, token: &Address, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let key = DataKey::DepositorLocked(depositor.clone(), token.clone());
+        let locked: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(locked + delta));
+    }
+
+    /// Update the lock policy (admin only).
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `min_deadline_horizon` is zero or above `max_deadline_horizon`
+    /// * `InvalidAmount` - `min_amount` is not positive, is above `max_amount`,
+    ///   or the per-depositor cap is not positive
+    pub fn set_lock_policy(env: Env, policy: LockPolicy) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if policy.min_deadline_horizon == 0
+            || policy.min_deadline_horizon > policy.max_deadline_horizon
+        {
+            return Err(Error::InvalidDeadline);
+        }
+        if policy.min_amount <= 0 || policy.min_amount > policy.max_amount {
+            return Err(Error::InvalidAmount);
+        }
+        if let Some(cap) = policy.max_locked_per_depositor {
+            if cap <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+        }
+
+        env.storage().instance().set(&DataKey::LockPolicy, &policy);
+
+        events::emit_lock_policy_updated(
+            &env,
+            events::LockPolicyUpdated {
+                min_deadline_horizon: policy.min_deadline_horizon,
+                max_deadline_horizon: policy.max_deadline_horizon,
+                min_amount: policy.min_amount,
+                max_amount: policy.max_amount,
+                max_locked_per_depositor: policy.max_locked_per_depositor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the lock policy enforced on new escrows (view function)
+    pub fn get_lock_policy(env: Env) -> LockPolicy {
+        Self::get_lock_policy_internal(&env)
+    }
+
+    /// Get the amount a depositor currently has locked in `token` (view function)
+    pub fn get_depositor_locked(env: Env, depositor: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DepositorLocked(depositor, token))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Pause and Emergency Functions
+    // ========================================================================
+
+    /// Check if contract is paused (internal helper)
+    fn is_paused_internal(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&DataKey::IsPaused)
+            .unwrap_or(false)
+    }
+
+    /// Get pause status (view function)
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    /// Pause the contract (admin only)
+    /// Prevents new fund locks, releases, and refunds
+    pub fn pause(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if Self::is_paused_internal(&env) {
+            return Ok(()); // Already paused, idempotent
+        }
+
+        env.storage().persistent().set(&DataKey::IsPaused, &true);
+
+        emit_contract_paused(
+            &env,
+            ContractPaused {
+                paused_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Unpause the contract (admin only)
+    /// Resumes normal operations
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !Self::is_paused_internal(&env) {
+            return Ok(()); // Already unpaused, idempotent
+        }
+
+        env.storage().persistent().set(&DataKey::IsPaused, &false);
+
+        emit_contract_unpaused(
+            &env,
+            ContractUnpaused {
+                unpaused_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Emergency withdrawal for all contract funds in `token` (admin only, only when paused)
+    /// This function allows admins to recover all contract funds in case of critical
+    /// security issues or unrecoverable bugs. It can only be called when the contract
+    /// is paused to prevent misuse.
+    pub fn emergency_withdraw(env: Env, token: Address, recipient: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // Only allow emergency withdrawal when contract is paused
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        let client = token::Client::new(&env, &token);
+
+        // Get contract balance
+        let balance = client.balance(&env.current_contract_address());
+
+        if balance <= 0 {
+            return Ok(()); // No funds to withdraw
+        }
+
+        // Transfer all funds to recipient; this includes any accrued fees and
+        // claimable fee shares, so every fee ledger and total for the token
+        // is cleared to keep them from paying out tokens that are gone
+        client.transfer(&env.current_contract_address(), &recipient, &balance);
+        let claimants: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FeeClaimants(token.clone()))
+            .unwrap_or(vec![&env]);
+        for claimant in claimants.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::FeeBalance(claimant, token.clone()));
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FeeClaimants(token.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AccruedFees(token.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TokenTotals(token.clone()));
+
+        emit_emergency_withdrawal(
+            &env,
+            EmergencyWithdrawal {
+                withdrawn_by: admin.clone(),
+                token,
+                amount: balance,
+                recipient: recipient.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `token` - Token to lock; must be on the accepted token allowlist
+    /// * `referrer` - Optional referrer credited with the referral share of
+    ///   this bounty's lock and release fees
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully locked
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    /// * `Err(Error::TokenNotAccepted)` - Token is not on the allowlist
+    /// * `Err(Error::InvalidReferrer)` - Depositor named itself as referrer
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from depositor to contract
+    /// - Creates Escrow record in persistent storage
+    /// - Emits FundsLocked event
+    ///
+    /// # Authorization
+    /// - Depositor must authorize the transaction
+    /// - Depositor must have sufficient token balance
+    /// - Depositor must have approved contract for token transfer
+    ///
+    /// # Security Considerations
+    /// - Bounty ID must be unique (prevents overwrites)
+    /// - Amount must be positive (enforced by token contract)
+    /// - Deadline should be reasonable (recommended: 7-90 days)
+    /// - Token transfer is atomic with state update
+    ///
+    /// # Events
+    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline, token }`
+    ///
+    /// # Example
+    /// ```rust
+    /// let depositor = Address::from_string("GDEPOSIT...");
+    /// let amount = 1000_0000000; // 1000 USDC
+    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    ///
+    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline, &usdc, &None, &None)?;
+    /// // Funds are now locked and can be released or refunded
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage write + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to approve token contract before calling
+    /// - Using a bounty ID that already exists
+    /// - Setting deadline in the past or too far in the future
+    #[allow(clippy::too_many_arguments)]
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+        referrer: Option<Address>,
+        correlation_id: Option<String>,
+    ) -> Result<(), Error> {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::lock_funds_internal(
+                env,
+                depositor.clone(),
+                LockFundsItem {
+                    bounty_id,
+                    depositor,
+                    amount,
+                    deadline,
+                    token,
+                },
+                referrer,
+            )
+        })
+    }
+
+    /// Shared implementation of `lock_funds` and `lock_funds_from` (internal helper).
+    ///
+    /// `initiator` must authorize the call and is rate limited. Funds are
+    /// pulled from `item.depositor`, by direct transfer when the initiator is
+    /// the depositor, or through the depositor's allowance otherwise.
+    fn lock_funds_internal(
+        env: Env,
+        initiator: Address,
+        item: LockFundsItem,
+        referrer: Option<Address>,
+    ) -> Result<(), Error> {
+        let LockFundsItem {
+            bounty_id,
+            depositor,
+            amount,
+            deadline,
+            token,
+        } = item;
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, initiator.clone());
+
+        let start = env.ledger().timestamp();
+        let caller = initiator.clone();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        // Verify initiator authorization (the depositor itself, or an operator)
+        initiator.require_auth();
+
+        // Ensure contract is initialized
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        if let Err(err) = Self::check_lock_policy(&env, &depositor, &token, amount, deadline) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+        if let Err(err) = Self::ensure_not_denylisted(&env, &depositor) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::NotInitialized);
+        }
+
+        // Prevent duplicate bounty IDs
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyExists);
+        }
+
+        if !Self::is_token_accepted_internal(&env, &token) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::TokenNotAccepted);
+        }
+
+        // Record the referrer first so the lock fee already pays its share
+        if let Some(referrer) = referrer {
+            if referrer == depositor {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+                env.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(Error::InvalidReferrer);
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::Referrer(bounty_id), &referrer);
+        }
+
+        Self::lock_escrow(
+            &env, &initiator, &depositor, bounty_id, amount, deadline, &token,
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+
+        Ok(())
+    }
+
+    /// Releases escrowed funds to a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release funds for
+    /// * `contributor` - Address to receive the funds
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully released
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - Caller is not the admin
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    /// * `Err(Error::DelayedReleaseRequired)` - Bounty needs `initiate_release`
+    /// * `Err(Error::ReleasePending)` - A delayed release is already pending
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to contributor
+    /// - Updates escrow status to Released
+    /// - Emits FundsReleased event
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only admin can call this function
+    /// - Admin address must match initialization value
+    ///
+    /// # Security Considerations
+    /// - This is the most security-critical function
+    /// - Admin should verify task completion off-chain before calling
+    /// - Once released, funds cannot be retrieved
+    /// - Recipient address should be verified carefully
+    /// - Consider implementing multi-sig for admin
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
+    ///
+    /// # Example
+    /// ```rust
+    /// // After verifying task completion off-chain:
+    /// let contributor = Address::from_string("GCONTRIB...");
+    ///
+    /// // Admin calls release
+    /// escrow_client.release_funds(&42, &contributor, &None)?;
+    /// // Funds transferred to contributor, escrow marked as Released
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage update + event emission
+    ///
+    /// # Best Practices
+    /// 1. Verify contributor identity off-chain
+    /// 2. Confirm task completion before release
+    /// 3. Log release decisions in backend system
+    /// 4. Monitor release events for anomalies
+    /// 5. Use `initiate_release`/`finalize_release` for high-value bounties
+    pub fn release_funds(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        correlation_id: Option<String>,
+    ) -> Result<(), Error> {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::release_funds_internal(env, bounty_id, contributor)
+        })
+    }
+
+    /// Implementation of `release_funds` (internal helper).
+    fn release_funds_internal(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Ensure contract is initialized
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::NotInitialized);
+        }
+
+        // Verify admin authorization
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::ContractPaused);
+        }
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, admin.clone());
+
+        admin.require_auth();
+
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::FundsNotLocked);
+        }
+
+        if escrow.frozen {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyFrozen);
+        }
+        if let Err(err) = Self::ensure_not_denylisted(&env, &contributor) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
+        // Contest bounties are paid out through finalize_contest
+        if env.storage().persistent().has(&DataKey::Contest(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::ContestBounty);
+        }
+
+        // Bounties above the challenge threshold must go through initiate_release
+        if Self::challenge_period_for(&env, bounty_id, &escrow) > 0 {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::DelayedReleaseRequired);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingRelease(bounty_id))
+        {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::ReleasePending);
+        }
+
+        Self::settle_release(&env, bounty_id, &mut escrow, &contributor)?;
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("release"), duration);
+        Ok(())
+    }
+
+    /// Locks funds for a bounty on behalf of a sponsor, using a token
+    /// allowance instead of a live sponsor signature.
+    ///
+    /// The sponsor approves this contract once with the token's `approve`;
+    /// an operator registered with `set_lock_operator` can then create
+    /// bounties funded from that allowance. The sponsor is recorded as the
+    /// escrow's depositor (and receives refunds); the operator is recorded as
+    /// its `initiator`.
+    ///
+    /// # Arguments
+    /// * `operator` - Registered lock operator (must authorize)
+    /// * `item` - Bounty to create; `item.depositor` is the sponsor whose
+    ///   allowance funds it
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Operator is not registered
+    /// * `InsufficientAllowance` - Sponsor's allowance to this contract is below `item.amount`
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_from(
+        env: Env,
+        operator: Address,
+        item: LockFundsItem,
+        correlation_id: Option<String>,
+    ) -> Result<(), Error> {
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::LockOperator(operator.clone()))
+        {
+            return Err(Error::Unauthorized);
+        }
+
+        let client = token::Client::new(&env, &item.token);
+        if client.allowance(&item.depositor, &env.current_contract_address()) < item.amount {
+            return Err(Error::InsufficientAllowance);
+        }
+
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::lock_funds_internal(env, operator, item, None)
+        })
+    }
+
+    /// Register or remove an operator allowed to call `lock_funds_from` (admin only).
+    pub fn set_lock_operator(env: Env, operator: Address, allowed: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if allowed {
+            env.storage()
+                .instance()
+                .set(&DataKey::LockOperator(operator.clone()), &true);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&DataKey::LockOperator(operator.clone()));
+        }
+
+        events::emit_lock_operator_updated(
+            &env,
+            events::LockOperatorUpdated {
+                operator,
+                allowed,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Check whether an address is a registered lock operator (view function)
+    pub fn is_lock_operator(env: Env, operator: Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::LockOperator(operator))
+    }
+
+    /// Locks and releases a bounty in a single call, for rewarding work
+    /// that is already done.
+    ///
+    /// Funds move from the depositor into the escrow and straight out to the
+    /// contributor, applying the lock fee and then the release fee exactly as
+    /// `lock_funds` followed by `release_funds` would. The resulting escrow
+    /// record is `Released`, and `FundsLocked` and `FundsReleased` are both
+    /// emitted so the audit trail matches the two-step flow.
+    ///
+    /// # Arguments
+    /// * `depositor` - Address funding the reward (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `contributor` - Address receiving the reward
+    /// * `amount` - Gross token amount taken from the depositor
+    /// * `token` - Token to pay in; must be on the accepted token allowlist
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
+    ///
+    /// # Authorization
+    /// - Depositor and admin must both authorize the transaction
+    /// - Rate limiting is applied once, to the depositor
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `NotInitialized` - Contract not initialized
+    /// * `InvalidAmount` - Amount is zero or negative
+    /// * `BountyExists` - Bounty ID already in use
+    /// * `TokenNotAccepted` - Token is not on the allowlist
+    pub fn reward(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+        token: Address,
+        correlation_id: Option<String>,
+    ) -> Result<(), Error> {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::reward_internal(env, depositor, bounty_id, contributor, amount, token)
+        })
+    }
+
+    /// Implementation of `reward` (internal helper).
+    fn reward_internal(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+        token: Address,
+    ) -> Result<(), Error> {
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+
+        let start = env.ledger().timestamp();
+
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("reward"), depositor, false);
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            monitoring::track_operation(&env, symbol_short!("reward"), depositor, false);
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        depositor.require_auth();
+        admin.require_auth();
+
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+
+        if let Err(err) = Self::check_amount_policy(&env, amount) {
+            monitoring::track_operation(&env, symbol_short!("reward"), depositor, false);
+            return Err(err);
+        }
+
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("reward"), depositor, false);
+            return Err(Error::BountyExists);
+        }
+
+        if !Self::is_token_accepted_internal(&env, &token) {
+            monitoring::track_operation(&env, symbol_short!("reward"), depositor, false);
+            return Err(Error::TokenNotAccepted);
+        }
+
+        if let Err(err) = Self::ensure_not_denylisted(&env, &depositor)
+            .and_then(|_| Self::ensure_not_denylisted(&env, &contributor))
+        {
+            monitoring::track_operation(&env, symbol_short!("reward"), depositor, false);
+            return Err(err);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        // The work is already done, so the escrow is due immediately
+        let mut escrow = Self::lock_escrow(
+            &env,
+            &depositor,
+            &depositor,
+            bounty_id,
+            amount,
+            env.ledger().timestamp(),
+            &token,
+        );
+        Self::settle_release(&env, bounty_id, &mut escrow, &contributor)?;
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("reward"), depositor, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("reward"), duration);
+
+        Ok(())
+    }
+
+    /// Moves `amount` from the depositor into escrow and records a Locked
+    /// escrow (internal helper).
+    ///
+    /// When `initiator` differs from the depositor, funds are pulled with
+    /// `transfer_from` against the depositor's allowance to this contract.
+    /// Deducts the lock fee when enabled and emits `FundsLocked`. Callers are
+    /// responsible for authorization and input validation.
+    fn lock_escrow(
+        env: &Env,
+        initiator: &Address,
+        depositor: &Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: &Address,
+    ) -> Escrow {
+        // Get token contract and transfer funds
+        let client = token::Client::new(env, token);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.lock_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        let contract_address = env.current_contract_address();
+        let pull = |to: &Address, value: &i128| {
+            if initiator == depositor {
+                client.transfer(depositor, to, value);
+            } else {
+                client.transfer_from(&contract_address, depositor, to, value);
+            }
+        };
+
+        // Transfer the full amount from depositor to contract; the fee stays
+        // in the contract's fee ledger until withdrawn
+        pull(&contract_address, &amount);
+
+        if fee_amount > 0 {
+            let splits = Self::collect_fee_with_referral(
+                env,
+                bounty_id,
+                &fee_config,
+                token,
+                fee_amount,
+                events::FeeOperationType::Lock,
+            );
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Lock,
+                    amount: fee_amount,
+                    fee_rate: fee_config.lock_fee_rate,
+                    token: token.clone(),
+                    splits,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        Self::update_token_totals(env, token, net_amount, fee_amount);
+        Self::adjust_depositor_locked(env, depositor, token, net_amount);
+        Self::update_depositor_stats(env, depositor, token, amount, 0, fee_amount);
+
+        // Create escrow record
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: net_amount, // Store net amount (after fee)
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![env],
+            remaining_amount: net_amount, // The lock fee is not refundable
+            token: token.clone(),
+            initiator: initiator.clone(),
+            frozen: false,
+        };
+
+        // Store in persistent storage with extended TTL
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // Emit event for off-chain indexing
+        emit_funds_locked(
+            env,
+            FundsLocked {
+                bounty_id,
+                amount: net_amount, // Emit net amount (after fee)
+                depositor: depositor.clone(),
+                deadline,
+                token: token.clone(),
+                initiator: initiator.clone(),
+            },
+        );
+
+        escrow
+    }
+
+    /// Pays out a Locked escrow to the contributor (internal helper).
+    ///
+    /// Marks the escrow as Released before transferring, deducts the release
+    /// fee when enabled, and emits `FundsReleased`. Returns the net amount paid.
+    fn settle_release(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &mut Escrow,
+        contributor: &Address,
+    ) -> Result<i128, Error> {
+        escrow.status = EscrowStatus::Released;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), escrow);
+
+        let gross = escrow.amount + Self::settle_boost(env, bounty_id, escrow);
+        let net_amount =
+            Self::transfer_with_release_fee(env, bounty_id, escrow, contributor, gross)?;
+
+        // Update escrow state - mark as released and set remaining_amount to 0
+        Self::adjust_depositor_locked(
+            env,
+            &escrow.depositor,
+            &escrow.token,
+            -escrow.remaining_amount,
+        );
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), escrow);
+
+        // Emit release event
+        emit_funds_released(
+            env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount, // Emit net amount (after fee)
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+                token: escrow.token.clone(),
+            },
+        );
+
+        Ok(net_amount)
+    }
+
+    /// Transfers `gross` of an escrow's token from the contract to
+    /// `recipient`, deducting the release fee when enabled (internal helper).
+    /// Every release, reward, dispute, contest and stream payout goes through
+    /// here, so this is where the KYC policy is enforced. Returns the net
+    /// amount paid.
+    fn transfer_with_release_fee(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        recipient: &Address,
+        gross: i128,
+    ) -> Result<i128, Error> {
+        Self::ensure_attested(env, recipient, gross)?;
+
+        let token = &escrow.token;
+        let client = token::Client::new(env, token);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
+            Self::calculate_fee(gross, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = gross - fee_amount;
+
+        // Transfer net amount to recipient
+        client.transfer(&env.current_contract_address(), recipient, &net_amount);
+        Self::record_receipt(env, bounty_id, recipient, token, net_amount);
+        Self::update_contributor_stats(env, recipient, token, net_amount, fee_amount);
+
+        // Keep the fee in the contract's fee ledger
+        if fee_amount > 0 {
+            let splits = Self::collect_fee_with_referral(
+                env,
+                bounty_id,
+                &fee_config,
+                token,
+                fee_amount,
+                events::FeeOperationType::Release,
+            );
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee_amount,
+                    fee_rate: fee_config.release_fee_rate,
+                    token: token.clone(),
+                    splits,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        Self::update_token_totals(env, token, -gross, fee_amount);
+
+        Ok(net_amount)
+    }
+
+    /// Adjusts the locked liability and collected fees for a token (internal helper).
+    ///
+    /// `locked_delta` is always on the escrow's `remaining_amount` basis,
+    /// i.e. net of the lock fee, so locks and payouts cancel out exactly.
+    fn update_token_totals(env: &Env, token: &Address, locked_delta: i128, fees: i128) {
+        let key = DataKey::TokenTotals(token.clone());
+        let mut totals: TokenTotals = env.storage().persistent().get(&key).unwrap_or(TokenTotals {
+            locked: 0,
+            fees_collected: 0,
+        });
+        totals.locked += locked_delta;
+        totals.fees_collected += fees;
+        env.storage().persistent().set(&key, &totals);
+    }
+
+    // ========================================================================
+    // Delayed Release (Challenge Period)
+    // ========================================================================
+
+    /// Get challenge configuration (internal helper)
+    fn get_challenge_config_internal(env: &Env) -> ChallengeConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChallengeConfig)
+            .unwrap_or(ChallengeConfig {
+                challenge_period: 0,
+                amount_threshold: 0,
+            })
+    }
+
+    /// Returns the challenge window that applies to a bounty, in seconds.
+    /// A per-bounty override takes precedence over the amount threshold rule.
+    fn challenge_period_for(env: &Env, bounty_id: u64, escrow: &Escrow) -> u64 {
+        if let Some(period) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::ChallengePeriod(bounty_id))
+        {
+            return period;
+        }
+        let config = Self::get_challenge_config_internal(env);
+        if config.challenge_period > 0 && escrow.amount >= config.amount_threshold {
+            config.challenge_period
+        } else {
+            0
+        }
+    }
+
+    /// Update the delayed-release configuration (admin only).
+    ///
+    /// Escrows with an amount at or above `amount_threshold` can then only be
+    /// paid out through `initiate_release` followed by `finalize_release`.
+    /// Setting `challenge_period` to zero disables the threshold rule.
+    pub fn set_challenge_config(
+        env: Env,
+        challenge_period: u64,
+        amount_threshold: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if amount_threshold < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().instance().set(
+            &DataKey::ChallengeConfig,
+            &ChallengeConfig {
+                challenge_period,
+                amount_threshold,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the delayed-release configuration (view function)
+    pub fn get_challenge_config(env: Env) -> ChallengeConfig {
+        Self::get_challenge_config_internal(&env)
+    }
+
+    /// Override the challenge window for a single bounty (admin only).
+    ///
+    /// `Some(seconds)` forces that window regardless of the amount threshold
+    /// (`Some(0)` exempts the bounty); `None` clears the override.
+    pub fn set_bounty_challenge_period(
+        env: Env,
+        bounty_id: u64,
+        challenge_period: Option<u64>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        match challenge_period {
+            Some(period) => env
+                .storage()
+                .persistent()
+                .set(&DataKey::ChallengePeriod(bounty_id), &period),
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::ChallengePeriod(bounty_id)),
+        }
+
+        Ok(())
+    }
+
+    /// Starts a delayed release of a Locked escrow to `contributor` (admin only).
+    ///
+    /// The depositor may object with `challenge_release` until the challenge
+    /// window closes; afterwards anyone can call `finalize_release`.
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Escrow is not in Locked state
+    /// * `ContestBounty` - Contests are paid with `finalize_contest`
+    /// * `ReleasePending` - A release is already pending for this bounty
+    ///
+    /// # Events
+    /// Emits: `ReleaseInitiated { bounty_id, contributor, finalize_after, timestamp }`
+    pub fn initiate_release(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        admin.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if env.storage().persistent().has(&DataKey::Contest(bounty_id)) {
+            return Err(Error::ContestBounty);
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingRelease(bounty_id))
+        {
+            return Err(Error::ReleasePending);
+        }
+
+        let now = env.ledger().timestamp();
+        let finalize_after =
+            now.saturating_add(Self::challenge_period_for(&env, bounty_id, &escrow));
+
+        let pending = PendingRelease {
+            bounty_id,
+            contributor: contributor.clone(),
+            initiated_at: now,
+            finalize_after,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingRelease(bounty_id), &pending);
+
+        events::emit_release_initiated(
+            &env,
+            events::ReleaseInitiated {
+                bounty_id,
+                contributor,
+                finalize_after,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Depositor objects to a pending release during the challenge window.
+    ///
+    /// Moves the escrow to `Disputed`; funds stay in the contract until the
+    /// admin calls `resolve_dispute`.
+    ///
+    /// # Errors
+    /// * `NoPendingRelease` - No release has been initiated for this bounty
+    /// * `ChallengePeriodEnded` - The challenge window has already closed
+    pub fn challenge_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        escrow.depositor.require_auth();
+
+        let pending: PendingRelease = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingRelease(bounty_id))
+            .ok_or(Error::NoPendingRelease)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= pending.finalize_after {
+            return Err(Error::ChallengePeriodEnded);
+        }
+
+        escrow.status = EscrowStatus::Disputed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        events::emit_release_challenged(
+            &env,
+            events::ReleaseChallenged {
+                bounty_id,
+                challenged_by: escrow.depositor.clone(),
+                contributor: pending.contributor,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Completes a pending release once its challenge window has closed.
+    /// Can be called by anyone.
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `NoPendingRelease` - No release has been initiated for this bounty
+    /// * `ChallengePeriodActive` - The challenge window is still open
+    /// * `FundsNotLocked` - Escrow was disputed or is otherwise not Locked
+    /// * `ContestBounty` - Contests are paid with `finalize_contest`
+    pub fn finalize_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        let pending: PendingRelease = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingRelease(bounty_id))
+            .ok_or(Error::NoPendingRelease)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if env.storage().persistent().has(&DataKey::Contest(bounty_id)) {
+            return Err(Error::ContestBounty);
+        }
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+
+        if env.ledger().timestamp() < pending.finalize_after {
+            return Err(Error::ChallengePeriodActive);
+        }
+        Self::ensure_not_denylisted(&env, &pending.contributor)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingRelease(bounty_id));
+
+        Self::settle_release(&env, bounty_id, &mut escrow, &pending.contributor)?;
+
+        Ok(())
+    }
+
+    /// Resolves a disputed release (admin only).
+    ///
+    /// If `release_to_contributor` is true the pending release is paid out
+    /// immediately; otherwise the escrow returns to `Locked` and the pending
+    /// release is discarded, leaving the funds refundable.
+    pub fn resolve_dispute(
+        env: Env,
+        bounty_id: u64,
+        release_to_contributor: bool,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotDisputed);
+        }
+        if release_to_contributor && escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+
+        let pending: PendingRelease = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingRelease(bounty_id))
+            .ok_or(Error::NoPendingRelease)?;
+        if release_to_contributor {
+            Self::ensure_not_denylisted(&env, &pending.contributor)?;
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingRelease(bounty_id));
+
+        if release_to_contributor {
+            Self::settle_release(&env, bounty_id, &mut escrow, &pending.contributor)?;
+        } else {
+            escrow.status = EscrowStatus::Locked;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+        }
+
+        events::emit_dispute_resolved(
+            &env,
+            events::DisputeResolved {
+                bounty_id,
+                resolved_by: admin,
+                released: release_to_contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the pending delayed release for a bounty, if any (view function).
+    pub fn get_pending_release(env: Env, bounty_id: u64) -> Option<PendingRelease> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingRelease(bounty_id))
+    }
+
+    // ========================================================================
+    // Competitive Bounties (Contests)
+    // ========================================================================
+
+    /// Locks funds for a competitive bounty with a ranked prize table.
+    ///
+    /// Behaves like `lock_funds` (same validation, fees and events) and
+    /// additionally stores the prize table. Entrants register with
+    /// `submit_entry` until the deadline; the admin or `judge` then pays the
+    /// winners with `finalize_contest`. The contest cannot be refunded or
+    /// cancelled until `CONTEST_JUDGING_WINDOW` after the deadline.
+    ///
+    /// # Arguments
+    /// * `token` - Token to lock; must be on the accepted token allowlist
+    /// * `contest` - Prize table and optional judge key (see `ContestConfig`)
+    ///
+    /// # Errors
+    /// * `InvalidPrizeTable` - Table is empty, longer than `MAX_BATCH_SIZE`,
+    ///   contains a zero share, or sums to more than 10,000
+    /// * Any error returned by `lock_funds`
+    pub fn lock_contest_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+        contest: ContestConfig,
+    ) -> Result<(), Error> {
+        let prize_count = contest.prize_shares.len();
+        if prize_count == 0 || prize_count > MAX_BATCH_SIZE {
+            return Err(Error::InvalidPrizeTable);
+        }
+        let mut total_shares: i128 = 0;
+        for share in contest.prize_shares.iter() {
+            if share == 0 {
+                return Err(Error::InvalidPrizeTable);
+            }
+            total_shares += share as i128;
+        }
+        if total_shares > BASIS_POINTS {
+            return Err(Error::InvalidPrizeTable);
+        }
+
+        Self::lock_funds(
+            env.clone(),
+            depositor,
+            bounty_id,
+            amount,
+            deadline,
+            token,
+            None,
+            None,
+        )?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contest(bounty_id), &contest);
+
+        Ok(())
+    }
+
+    /// Registers a contributor's submission to a contest bounty.
+    ///
+    /// # Authorization
+    /// - Contributor must authorize the transaction
+    ///
+    /// # Errors
+    /// * `NotContest` - Bounty is not a contest
+    /// * `FundsNotLocked` - Contest has already been finalized or refunded
+    /// * `SubmissionsClosed` - Deadline has passed
+    /// * `DuplicateSubmission` - Contributor already submitted
+    /// * `InvalidBatchSize` - Contest already has `MAX_BATCH_SIZE` entrants
+    pub fn submit_entry(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        contributor.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if !env.storage().persistent().has(&DataKey::Contest(bounty_id)) {
+            return Err(Error::NotContest);
+        }
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if env.ledger().timestamp() >= escrow.deadline {
+            return Err(Error::SubmissionsClosed);
+        }
+
+        let mut submissions: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submissions(bounty_id))
+            .unwrap_or(vec![&env]);
+
+        if submissions.contains(&contributor) {
+            return Err(Error::DuplicateSubmission);
+        }
+        if submissions.len() >= MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        submissions.push_back(contributor.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Submissions(bounty_id), &submissions);
+
+        events::emit_submission_registered(
+            &env,
+            events::SubmissionRegistered {
+                bounty_id,
+                contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Pays a contest's winners according to its prize table.
+    ///
+    /// `ranking[i]` receives `prize_shares[i]` of the escrowed amount, less
+    /// the release fee. Shares for ranks with no winner, and any share not
+    /// allocated by the prize table, are refunded to the depositor's refund
+    /// address. The escrow ends in `Released` state.
+    ///
+    /// # Authorization
+    /// - `caller` must be the admin or the contest judge, and must authorize
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is neither admin nor judge
+    /// * `SubmissionsOpen` - The contest deadline has not passed yet
+    /// * `InvalidPrizeTable` - More winners than prize ranks
+    /// * `SubmissionNotFound` - A ranked address never submitted
+    /// * `DuplicateSubmission` - The same address is ranked twice
+    /// * `AddressDenylisted` - A winner, or the refund address of an
+    ///   unallocated remainder, is on the denylist
+    ///
+    /// # Events
+    /// Emits `ContestPrizePaid` per winner, `FundsRefunded` for any remainder,
+    /// and `ContestFinalized` once.
+    pub fn finalize_contest(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        ranking: Vec<Address>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let contest: ContestConfig = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contest(bounty_id))
+            .ok_or(Error::NotContest)?;
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin && Some(caller.clone()) != contest.judge {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+        if env.ledger().timestamp() < escrow.deadline {
+            return Err(Error::SubmissionsOpen);
+        }
+
+        if ranking.len() > contest.prize_shares.len() {
+            return Err(Error::InvalidPrizeTable);
+        }
+
+        let submissions: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submissions(bounty_id))
+            .unwrap_or(vec![&env]);
+        let pool = escrow.amount;
+        let mut allocated: i128 = 0;
+        for (i, winner) in ranking.iter().enumerate() {
+            if !submissions.contains(&winner) {
+                return Err(Error::SubmissionNotFound);
+            }
+            if ranking.first_index_of(&winner) != Some(i as u32) {
+                return Err(Error::DuplicateSubmission);
+            }
+            Self::ensure_not_denylisted(&env, &winner)?;
+            allocated += pool * contest.prize_shares.get(i as u32).unwrap() as i128 / BASIS_POINTS;
+        }
+        if allocated < pool {
+            Self::ensure_not_denylisted(&env, &Self::refund_address_for(&env, bounty_id, &escrow))?;
+        }
+
+        // Update state before transferring
+        Self::adjust_depositor_locked(
+            &env,
+            &escrow.depositor,
+            &escrow.token,
+            -escrow.remaining_amount,
+        );
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let timestamp = env.ledger().timestamp();
+        let mut awarded: i128 = 0;
+        for (i, winner) in ranking.iter().enumerate() {
+            let share = contest.prize_shares.get(i as u32).unwrap();
+            let prize = pool * share as i128 / BASIS_POINTS;
+            if prize == 0 {
+                continue;
+            }
+            awarded += prize;
+
+            let net_amount =
+                Self::transfer_with_release_fee(&env, bounty_id, &escrow, &winner, prize)?;
+            events::emit_contest_prize_paid(
+                &env,
+                events::ContestPrizePaid {
+                    bounty_id,
+                    rank: i as u32 + 1,
+                    winner,
+                    amount: net_amount,
+                    timestamp,
+                },
+            );
+        }
+
+        let remainder = pool - awarded;
+        if remainder > 0 {
+            let refund_to = Self::refund_address_for(&env, bounty_id, &escrow);
+            let client = token::Client::new(&env, &escrow.token);
+            client.transfer(&env.current_contract_address(), &refund_to, &remainder);
+            Self::update_token_totals(&env, &escrow.token, -remainder, 0);
+            Self::update_depositor_stats(&env, &escrow.depositor, &escrow.token, 0, remainder, 0);
+
+            escrow.refund_history.push_back(RefundRecord {
+                amount: remainder,
+                recipient: refund_to.clone(),
+                mode: RefundMode::Partial,
+                timestamp,
+            });
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    bounty_id,
+                    amount: remainder,
+                    refund_to,
+                    timestamp,
+                    refund_mode: RefundMode::Partial,
+                    remaining_amount: 0,
+                    token: escrow.token.clone(),
+                },
+            );
+        }
+
+        events::emit_contest_finalized(
+            &env,
+            events::ContestFinalized {
+                bounty_id,
+                finalized_by: caller,
+                winners: ranking.len(),
+                awarded,
+                refunded: remainder,
+                timestamp,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Rejects refunds and cancellations of a contest that may still be
+    /// judged (internal helper)
+    fn ensure_contest_closed(env: &Env, bounty_id: u64, escrow: &Escrow) -> Result<(), Error> {
+        if env.storage().persistent().has(&DataKey::Contest(bounty_id))
+            && env.ledger().timestamp() < escrow.deadline.saturating_add(CONTEST_JUDGING_WINDOW)
+        {
+            return Err(Error::ContestNotFinalized);
+        }
+        Ok(())
+    }
+
+    /// Retrieves the prize table for a contest bounty (view function).
+    pub fn get_contest(env: Env, bounty_id: u64) -> Result<ContestConfig, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Contest(bounty_id))
+            .ok_or(Error::NotContest)
+    }
+
+    /// Lists contributors who submitted to a contest bounty (view function).
+    pub fn get_submissions(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submissions(bounty_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Refund funds with support for Full, Partial, and Custom refunds.
+    /// - Full: refunds all remaining funds to the refund address (depositor by default)
+    /// - Partial: refunds specified amount to the refund address (depositor by default)
+    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
+    ///
+    /// A contest bounty can only be refunded once `CONTEST_JUDGING_WINDOW`
+    /// has passed after its deadline (`ContestNotFinalized` until then).
+    ///
+    /// `correlation_id` is an optional caller-supplied id (e.g. a GitHub
+    /// delivery id) carried in the envelope of every event this call emits.
+    pub fn refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+        correlation_id: Option<String>,
+    ) -> Result<(), Error> {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::refund_internal(env, bounty_id, amount, recipient, mode)
+        })
+    }
+
+    /// Implementation of `refund` (internal helper).
+    fn refund_internal(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let caller = escrow.depositor.clone();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+        Self::ensure_contest_closed(&env, bounty_id, &escrow)?;
+
+        // A pending delayed release must be finalized or disputed first
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingRelease(bounty_id))
+        {
+            return Err(Error::ReleasePending);
+        }
+
+        // Verify deadline has passed
+        let now = env.ledger().timestamp();
+        let is_before_deadline = now < escrow.deadline;
+
+        // Determine refund amount and recipient
+        let refund_amount: i128;
+        let refund_recipient: Address;
+
+        match mode {
+            RefundMode::Full => {
+                refund_amount = escrow.remaining_amount;
+                refund_recipient = Self::refund_address_for(&env, bounty_id, &escrow);
+                if is_before_deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Partial => {
+                refund_amount = amount.unwrap_or(escrow.remaining_amount);
+                refund_recipient = Self::refund_address_for(&env, bounty_id, &escrow);
+                if is_before_deadline {
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Custom => {
+                refund_amount = amount.ok_or(Error::InvalidAmount)?;
+                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
+
+                // Custom refunds before deadline require admin approval
+                if is_before_deadline {
+                    if !env
+                        .storage()
+                        .persistent()
+                        .has(&DataKey::RefundApproval(bounty_id))
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+                    let approval: RefundApproval = env
+                        .storage()
+                        .persistent()
+                        .get(&DataKey::RefundApproval(bounty_id))
+                        .unwrap();
+
+                    // Verify approval matches request
+                    if approval.amount != refund_amount
+                        || approval.recipient != refund_recipient
+                        || approval.mode != mode
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+
+                    // Clear approval after use
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::RefundApproval(bounty_id));
+                }
+            }
+        }
+
+        // Validate amount
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::ensure_not_denylisted(&env, &refund_recipient)?;
+
+        // Transfer funds back to depositor
+        let client = token::Client::new(&env, &escrow.token);
+
+        // Check contract balance
+        let contract_balance = client.balance(&env.current_contract_address());
+        if contract_balance < refund_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // Transfer funds
+        client.transfer(
+            &env.current_contract_address(),
+            &refund_recipient,
+            &refund_amount,
+        );
+
+        // Update escrow state; a refund forfeits any boost, its reserve is
+        // simply part of the remaining amount
+        escrow.remaining_amount -= refund_amount;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Boost(bounty_id));
+        Self::update_token_totals(&env, &escrow.token, -refund_amount, 0);
+        Self::adjust_depositor_locked(&env, &escrow.depositor, &escrow.token, -refund_amount);
+        Self::update_depositor_stats(&env, &escrow.depositor, &escrow.token, 0, refund_amount, 0);
+
+        // Add to refund history
+        let refund_record = RefundRecord {
+            amount: refund_amount,
+            recipient: refund_recipient.clone(),
+            mode: mode.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        escrow.refund_history.push_back(refund_record);
+
+        // Update status
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // Emit refund event
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                bounty_id,
+                amount: refund_amount,
+                refund_to: refund_recipient,
+                timestamp: env.ledger().timestamp(),
+                refund_mode: mode.clone(),
+                remaining_amount: escrow.remaining_amount,
+                token: escrow.token.clone(),
+            },
+        );
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+
+        Ok(())
+    }
+
+    /// Cancels a bounty and returns the remaining funds to the depositor
+    /// (or its refund address, if one is set).
+    ///
+    /// Unlike `refund`, cancellation can happen at any time before the
+    /// bounty is paid out, but requires both the depositor and the admin to
+    /// authorize the same invocation. When fees are enabled, the configured
+    /// `cancel_fee_rate` is deducted and sent to the fee recipient.
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Escrow is not Locked or PartiallyRefunded
+    /// * `ReleasePending` - A delayed release is pending for this bounty
+    /// * `ContestNotFinalized` - The bounty is a contest still being judged
+    /// * `AddressDenylisted` - The refund address is on the denylist
+    ///
+    /// # Events
+    /// Emits: `BountyCancelled { bounty_id, depositor, refund_amount, fee_amount, timestamp }`
+    pub fn cancel_bounty(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        // Both parties must sign off on the cancellation
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        escrow.depositor.require_auth();
+        admin.require_auth();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+        Self::ensure_contest_closed(&env, bounty_id, &escrow)?;
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingRelease(bounty_id))
+        {
+            return Err(Error::ReleasePending);
+        }
+
+        let remaining = escrow.remaining_amount;
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.cancel_fee_rate > 0 {
+            Self::calculate_fee(remaining, fee_config.cancel_fee_rate)
+        } else {
+            0
+        };
+        let refund_amount = remaining - fee_amount;
+        let refund_to = Self::refund_address_for(&env, bounty_id, &escrow);
+        if refund_amount > 0 {
+            Self::ensure_not_denylisted(&env, &refund_to)?;
+        }
+
+        // Update state before transferring
+        escrow.status = EscrowStatus::Cancelled;
+        escrow.remaining_amount = 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundApproval(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Boost(bounty_id));
+
+        let client = token::Client::new(&env, &escrow.token);
+        Self::update_token_totals(&env, &escrow.token, -remaining, fee_amount);
+        Self::adjust_depositor_locked(&env, &escrow.depositor, &escrow.token, -remaining);
+        Self::update_depositor_stats(
+            &env,
+            &escrow.depositor,
+            &escrow.token,
+            0,
+            refund_amount,
+            fee_amount,
+        );
+
+        if refund_amount > 0 {
+            client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        }
+
+        if fee_amount > 0 {
+            let splits = Self::collect_fee(&env, &fee_config, &escrow.token, fee_amount);
+            events::emit_fee_collected(
+                &env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Cancel,
+                    amount: fee_amount,
+                    fee_rate: fee_config.cancel_fee_rate,
+                    token: escrow.token.clone(),
+                    splits,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        events::emit_bounty_cancelled(
+            &env,
+            events::BountyCancelled {
+                bounty_id,
+                depositor: escrow.depositor.clone(),
+                refund_amount,
+                fee_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("cancel"), escrow.depositor, true);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Time-Boosted Bounties
+    // ========================================================================
+
+    /// Boost accrued by `now`, capped at the reserve (internal helper)
+    fn boost_accrued(boost: &BoostConfig, now: u64) -> i128 {
+        const SECONDS_PER_DAY: u64 = 86_400;
+        let elapsed = now.saturating_sub(boost.start_time);
+        let accrued = match boost.curve {
+            BoostCurve::Linear => {
+                boost.rate_per_day.saturating_mul(elapsed as i128) / SECONDS_PER_DAY as i128
+            }
+            BoostCurve::Step => boost
+                .rate_per_day
+                .saturating_mul((elapsed / SECONDS_PER_DAY) as i128),
+        };
+        accrued.min(boost.reserve)
+    }
+
+    /// Amount a release would pay before fees: the escrow amount plus the
+    /// boost accrued so far (internal helper)
+    fn payable_amount_internal(env: &Env, bounty_id: u64, escrow: &Escrow) -> i128 {
+        let accrued = env
+            .storage()
+            .persistent()
+            .get::<_, BoostConfig>(&DataKey::Boost(bounty_id))
+            .map(|boost| Self::boost_accrued(&boost, env.ledger().timestamp()))
+            .unwrap_or(0);
+        escrow.amount + accrued
+    }
+
+    /// Settles a bounty's boost on release (internal helper).
+    ///
+    /// Returns the unused reserve to the escrow's refund address and returns
+    /// the accrued boost, which the caller adds to the payout. Returns zero
+    /// for bounties without a boost.
+    fn settle_boost(env: &Env, bounty_id: u64, escrow: &Escrow) -> i128 {
+        let boost: BoostConfig = match env.storage().persistent().get(&DataKey::Boost(bounty_id)) {
+            Some(boost) => boost,
+            None => return 0,
+        };
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Boost(bounty_id));
+
+        let accrued = Self::boost_accrued(&boost, env.ledger().timestamp());
+        let unused = boost.reserve - accrued;
+        let refund_to = Self::refund_address_for(env, bounty_id, escrow);
+        if unused > 0 {
+            let client = token::Client::new(env, &escrow.token);
+            client.transfer(&env.current_contract_address(), &refund_to, &unused);
+            Self::update_token_totals(env, &escrow.token, -unused, 0);
+            Self::update_depositor_stats(env, &escrow.depositor, &escrow.token, 0, unused, 0);
+        }
+
+        events::emit_boost_settled(
+            env,
+            events::BoostSettled {
+                bounty_id,
+                accrued,
+                returned: unused,
+                returned_to: refund_to,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        accrued
+    }
+
+    /// Locks a bounty together with a boost reserve that makes it grow
+    /// until it is released.
+    ///
+    /// `amount` is locked exactly as with `lock_funds`; `boost.reserve` is
+    /// transferred from the depositor on top of it. On release the
+    /// contributor is paid the base plus the boost accrued so far, and the
+    /// unused reserve goes back to the depositor (or its refund address). A
+    /// refund or cancellation returns the whole reserve with the base.
+    /// `boost.start_time` is ignored; the boost starts accruing at lock time.
+    ///
+    /// The reserve counts towards the lock policy: `amount` plus the reserve
+    /// must be within the amount bounds and the depositor's cap.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Reserve or rate is not positive, or `amount` plus
+    ///   the reserve is outside the lock policy bounds
+    /// * `DepositorCapExceeded` - The reserve takes the depositor over its cap
+    /// * Any error returned by `lock_funds`
+    pub fn lock_boosted_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        token: Address,
+        boost: BoostConfig,
+    ) -> Result<(), Error> {
+        if boost.reserve <= 0 || boost.rate_per_day <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::check_amount_policy(&env, amount.saturating_add(boost.reserve))?;
+
+        Self::lock_funds(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            token.clone(),
+            None,
+            None,
+        )?;
+        Self::check_depositor_cap(&env, &depositor, &token, boost.reserve)?;
+
+        // Pull the reserve and hold it as part of the escrow's remaining amount
+        let client = token::Client::new(&env, &token);
+        client.transfer(&depositor, &env.current_contract_address(), &boost.reserve);
+        Self::update_token_totals(&env, &token, boost.reserve, 0);
+        Self::adjust_depositor_locked(&env, &depositor, &token, boost.reserve);
+        Self::update_depositor_stats(&env, &depositor, &token, boost.reserve, 0, 0);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.remaining_amount += boost.reserve;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let boost = BoostConfig {
+            start_time: env.ledger().timestamp(),
+            ..boost
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Boost(bounty_id), &boost);
+
+        events::emit_boost_added(
+            &env,
+            events::BoostAdded {
+                bounty_id,
+                reserve: boost.reserve,
+                rate_per_day: boost.rate_per_day,
+                curve: boost.curve,
+                start_time: boost.start_time,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the boost attached to a bounty, if any (view function)
+    pub fn get_boost(env: Env, bounty_id: u64) -> Option<BoostConfig> {
+        env.storage().persistent().get(&DataKey::Boost(bounty_id))
+    }
+
+    // ========================================================================
+    // Streaming Payouts
+    // ========================================================================
+
+    /// Amount of a stream accrued at `now` (internal helper)
+    fn stream_accrued(stream: &Stream, now: u64) -> i128 {
+        if now <= stream.start_time {
+            return 0;
+        }
+        if now >= stream.end_time {
+            return stream.total;
+        }
+        let elapsed = (now - stream.start_time) as i128;
+        let duration = (stream.end_time - stream.start_time) as i128;
+        stream.total * elapsed / duration
+    }
+
+    /// Releases a bounty as a linear stream instead of a lump sum (admin only).
+    ///
+    /// The escrow moves to `Streaming` and its full `amount` accrues to
+    /// `contributor` between `start_time` and `end_time`. The release fee is
+    /// charged on each withdrawal.
+    ///
+    /// # Errors
+    /// * `FundsNotLocked` - Escrow is not Locked
+    /// * `ContestBounty` / `ReleasePending` - Bounty must be paid another way
+    /// * `BountyFrozen` / `AddressDenylisted` - Compliance checks failed
+    /// * `InvalidStreamWindow` - `end_time` is not after both `start_time` and now
+    pub fn start_stream(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<(), Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        // Only allow emergency withdrawal when contract is paused
-        if !Self::is_paused_internal(&env) {
-            return Err(Error::Unauthorized);
-        }
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
 
-        // Get contract balance
-        let balance = client.balance(&env.current_contract_address());
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+        if env.storage().persistent().has(&DataKey::Contest(bounty_id)) {
+            return Err(Error::ContestBounty);
+        }
+        if env.storage().persistent().has(&DataKey::Boost(bounty_id)) {
+            return Err(Error::BoostedBounty);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingRelease(bounty_id))
+        {
+            return Err(Error::ReleasePending);
+        }
+        Self::ensure_not_denylisted(&env, &contributor)?;
 
-        if balance <= 0 {
-            return Ok(()); // No funds to withdraw
+        if end_time <= start_time || end_time <= env.ledger().timestamp() {
+            return Err(Error::InvalidStreamWindow);
         }
 
-        // Transfer all funds to recipient
-        client.transfer(&env.current_contract_address(), &recipient, &balance);
+        let stream = Stream {
+            contributor: contributor.clone(),
+            start_time,
+            end_time,
+            total: escrow.amount,
+            withdrawn: 0,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(bounty_id), &stream);
 
-        emit_emergency_withdrawal(
+        escrow.status = EscrowStatus::Streaming;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        events::emit_stream_started(
             &env,
-            EmergencyWithdrawal {
-                withdrawn_by: admin.clone(),
-                amount: balance,
-                recipient: recipient.clone(),
-                timestamp: env.ledger().timestamp(),
+            events::StreamStarted {
+                bounty_id,
+                contributor,
+                total: stream.total,
+                start_time,
+                end_time,
             },
         );
 
         Ok(())
     }
 
-    /// Lock funds for a specific bounty.
+    /// Withdraws the accrued, not yet withdrawn part of a stream to its
+    /// contributor (contributor must authorize).
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `depositor` - Address depositing the funds (must authorize)
-    /// * `bounty_id` - Unique identifier for this bounty
-    /// * `amount` - Token amount to lock (in smallest denomination)
-    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// Once the whole stream has been withdrawn the escrow becomes `Released`.
     ///
     /// # Returns
-    /// * `Ok(())` - Funds successfully locked
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::BountyExists)` - Bounty ID already in use
-    ///
-    /// # State Changes
-    /// - Transfers `amount` tokens from depositor to contract
-    /// - Creates Escrow record in persistent storage
-    /// - Emits FundsLocked event
-    ///
-    /// # Authorization
-    /// - Depositor must authorize the transaction
-    /// - Depositor must have sufficient token balance
-    /// - Depositor must have approved contract for token transfer
-    ///
-    /// # Security Considerations
-    /// - Bounty ID must be unique (prevents overwrites)
-    /// - Amount must be positive (enforced by token contract)
-    /// - Deadline should be reasonable (recommended: 7-90 days)
-    /// - Token transfer is atomic with state update
-    ///
-    /// # Events
-    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
-    ///
-    /// # Example
-    /// ```rust
-    /// let depositor = Address::from_string("GDEPOSIT...");
-    /// let amount = 1000_0000000; // 1000 USDC
-    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
-    ///
-    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
-    /// // Funds are now locked and can be released or refunded
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage write + event emission
-    ///
-    /// # Common Pitfalls
-    /// - Forgetting to approve token contract before calling
-    /// - Using a bounty ID that already exists
-    /// - Setting deadline in the past or too far in the future
-    pub fn lock_funds(
-        env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
-
-        let start = env.ledger().timestamp();
-        let caller = depositor.clone();
-
-        // Check if contract is paused
+    /// The net amount paid to the contributor after the release fee.
+    pub fn withdraw_stream(env: Env, bounty_id: u64) -> Result<i128, Error> {
         if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
             return Err(Error::ContractPaused);
         }
 
-        // Verify depositor authorization
-        depositor.require_auth();
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Streaming {
+            return Err(Error::NotStreaming);
+        }
+        let mut stream: Stream = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Stream(bounty_id))
+            .ok_or(Error::NotStreaming)?;
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+        stream.contributor.require_auth();
+
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
+        Self::ensure_not_denylisted(&env, &stream.contributor)?;
 
-        if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::InvalidAmount);
+        let gross = Self::stream_accrued(&stream, env.ledger().timestamp()) - stream.withdrawn;
+        if gross <= 0 {
+            return Ok(0);
         }
 
-        if deadline <= env.ledger().timestamp() {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::InvalidDeadline);
+        // Update state before transferring
+        stream.withdrawn += gross;
+        escrow.remaining_amount -= gross;
+        Self::adjust_depositor_locked(&env, &escrow.depositor, &escrow.token, -gross);
+        if stream.withdrawn == stream.total {
+            Self::adjust_depositor_locked(
+                &env,
+                &escrow.depositor,
+                &escrow.token,
+                -escrow.remaining_amount,
+            );
+            escrow.remaining_amount = 0;
+            escrow.status = EscrowStatus::Released;
         }
-        if !env.storage().instance().has(&DataKey::Admin) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::NotInitialized);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(bounty_id), &stream);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let net_amount =
+            Self::transfer_with_release_fee(&env, bounty_id, &escrow, &stream.contributor, gross)?;
+
+        events::emit_stream_withdrawn(
+            &env,
+            events::StreamWithdrawn {
+                bounty_id,
+                contributor: stream.contributor,
+                amount: net_amount,
+                withdrawn_total: stream.withdrawn,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(net_amount)
+    }
+
+    /// Stops a stream (depositor must authorize).
+    ///
+    /// Whatever has accrued but not been withdrawn is paid to the contributor;
+    /// the unaccrued remainder is refunded to the escrow's refund address and
+    /// recorded in its refund history. The escrow then ends in a terminal
+    /// state: `Refunded` when a remainder went back, `Released` when the
+    /// stream had fully accrued to the contributor.
+    ///
+    /// A contributor who is denylisted or lacks a required attestation does
+    /// not block the stop: the accrued amount stays in escrow as a stream
+    /// ending now, which the contributor can withdraw once cleared, and the
+    /// escrow stays `Streaming` until then.
+    ///
+    /// # Returns
+    /// The amount refunded.
+    pub fn stop_stream(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
         }
 
-        // Prevent duplicate bounty IDs
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyExists);
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Streaming {
+            return Err(Error::NotStreaming);
         }
 
-        // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        escrow.depositor.require_auth();
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.lock_fee_rate)
+        if escrow.frozen {
+            return Err(Error::BountyFrozen);
+        }
+
+        let stream: Stream = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Stream(bounty_id))
+            .ok_or(Error::NotStreaming)?;
+        let now = env.ledger().timestamp();
+        let accrued = Self::stream_accrued(&stream, now);
+        let owed = accrued - stream.withdrawn;
+        let unaccrued = stream.total - accrued;
+
+        let refund_to = Self::refund_address_for(&env, bounty_id, &escrow);
+        Self::ensure_not_denylisted(&env, &refund_to)?;
+        let hold = owed > 0
+            && (Self::ensure_not_denylisted(&env, &stream.contributor).is_err()
+                || Self::ensure_attested(&env, &stream.contributor, owed).is_err());
+
+        // Update state before transferring
+        if hold {
+            // Keep what accrued for the contributor as a stream ending now
+            Self::adjust_depositor_locked(&env, &escrow.depositor, &escrow.token, -unaccrued);
+            escrow.remaining_amount = owed;
+        } else {
+            Self::adjust_depositor_locked(
+                &env,
+                &escrow.depositor,
+                &escrow.token,
+                -escrow.remaining_amount,
+            );
+            escrow.remaining_amount = 0;
+            escrow.status = if unaccrued > 0 {
+                EscrowStatus::Refunded
+            } else {
+                EscrowStatus::Released
+            };
+        }
+        if unaccrued > 0 {
+            escrow.refund_history.push_back(RefundRecord {
+                amount: unaccrued,
+                recipient: refund_to.clone(),
+                mode: if accrued == 0 {
+                    RefundMode::Full
+                } else {
+                    RefundMode::Partial
+                },
+                timestamp: now,
+            });
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        if hold {
+            env.storage().persistent().set(
+                &DataKey::Stream(bounty_id),
+                &Stream {
+                    end_time: now,
+                    total: accrued,
+                    ..stream.clone()
+                },
+            );
+        } else {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Stream(bounty_id));
+        }
+
+        let paid = if owed > 0 && !hold {
+            Self::transfer_with_release_fee(&env, bounty_id, &escrow, &stream.contributor, owed)?
         } else {
             0
         };
-        let net_amount = amount - fee_amount;
 
-        // Transfer net amount from depositor to contract
-        client.transfer(&depositor, &env.current_contract_address(), &net_amount);
+        if unaccrued > 0 {
+            let client = token::Client::new(&env, &escrow.token);
+            client.transfer(&env.current_contract_address(), &refund_to, &unaccrued);
+            Self::update_token_totals(&env, &escrow.token, -unaccrued, 0);
+            Self::update_depositor_stats(&env, &escrow.depositor, &escrow.token, 0, unaccrued, 0);
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(&depositor, &fee_config.fee_recipient, &fee_amount);
-            events::emit_fee_collected(
+            emit_funds_refunded(
                 &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Lock,
-                    amount: fee_amount,
-                    fee_rate: fee_config.lock_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
+                FundsRefunded {
+                    bounty_id,
+                    amount: unaccrued,
+                    refund_to: refund_to.clone(),
+                    timestamp: now,
+                    refund_mode: escrow.refund_history.last().unwrap().mode,
+                    remaining_amount: 0,
+                    token: escrow.token.clone(),
                 },
             );
         }
 
-        // Create escrow record
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            amount: net_amount, // Store net amount (after fee)
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
-            remaining_amount: amount,
-        };
+        events::emit_stream_stopped(
+            &env,
+            events::StreamStopped {
+                bounty_id,
+                paid_to_contributor: paid,
+                refunded: unaccrued,
+                refund_to,
+                timestamp: now,
+            },
+        );
+
+        Ok(unaccrued)
+    }
+
+    /// Retrieves the stream for a bounty, if any (view function)
+    pub fn get_stream(env: Env, bounty_id: u64) -> Option<Stream> {
+        env.storage().persistent().get(&DataKey::Stream(bounty_id))
+    }
+
+    /// Returns the gross amount the contributor could withdraw now (view function)
+    pub fn get_stream_withdrawable(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let stream: Stream = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Stream(bounty_id))
+            .ok_or(Error::NotStreaming)?;
+        Ok(Self::stream_accrued(&stream, env.ledger().timestamp()) - stream.withdrawn)
+    }
+
+    // ========================================================================
+    // Application Stakes
+    // ========================================================================
+
+    /// Loads an applicant's active stake (internal helper)
+    fn active_stake(
+        env: &Env,
+        bounty_id: u64,
+        applicant: &Address,
+    ) -> Result<ApplicationStake, Error> {
+        let stake: ApplicationStake = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Stake(bounty_id, applicant.clone()))
+            .ok_or(Error::StakeNotActive)?;
+        if stake.status != StakeStatus::Active {
+            return Err(Error::StakeNotActive);
+        }
+        Ok(stake)
+    }
+
+    /// Sets or clears the stake applicants must lock to apply to a bounty
+    /// (depositor only). Existing stakes are not affected.
+    pub fn set_stake_requirement(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        escrow.depositor.require_auth();
+
+        match amount {
+            Some(amount) => {
+                if amount <= 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::StakeRequirement(bounty_id), &amount);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::StakeRequirement(bounty_id)),
+        }
+
+        Ok(())
+    }
+
+    /// Get the stake required to apply to a bounty, if any (view function)
+    pub fn get_stake_requirement(env: Env, bounty_id: u64) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StakeRequirement(bounty_id))
+    }
+
+    /// Applies to a bounty by locking the required stake in the escrow's
+    /// token (applicant must authorize).
+    ///
+    /// # Errors
+    /// * `StakeNotRequired` - The bounty has no stake requirement
+    /// * `FundsNotLocked` - The bounty is no longer open
+    /// * `AlreadyApplied` - Applicant already holds an active stake
+    /// * `AddressDenylisted` - Applicant is on the denylist
+    pub fn apply_with_stake(env: Env, bounty_id: u64, applicant: Address) -> Result<(), Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        applicant.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StakeRequirement(bounty_id))
+            .ok_or(Error::StakeNotRequired)?;
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if Self::active_stake(&env, bounty_id, &applicant).is_ok() {
+            return Err(Error::AlreadyApplied);
+        }
+        Self::ensure_not_denylisted(&env, &applicant)?;
+
+        let now = env.ledger().timestamp();
+        let key = DataKey::Stake(bounty_id, applicant.clone());
+        let first_application = !env.storage().persistent().has(&key);
+        env.storage().persistent().set(
+            &key,
+            &ApplicationStake {
+                amount,
+                token: escrow.token.clone(),
+                status: StakeStatus::Active,
+                staked_at: now,
+            },
+        );
+        if first_application {
+            let mut applicants: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Applicants(bounty_id))
+                .unwrap_or(vec![&env]);
+            applicants.push_back(applicant.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::Applicants(bounty_id), &applicants);
+        }
 
-        // Store in persistent storage with extended TTL
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        let client = token::Client::new(&env, &escrow.token);
+        client.transfer(&applicant, &env.current_contract_address(), &amount);
+        Self::update_token_totals(&env, &escrow.token, amount, 0);
 
-        // Emit event for off-chain indexing
-        emit_funds_locked(
+        events::emit_stake_deposited(
             &env,
-            FundsLocked {
+            events::StakeDeposited {
                 bounty_id,
-                amount: net_amount, // Emit net amount (after fee)
-                depositor: depositor.clone(),
-                deadline,
+                applicant,
+                amount,
+                token: escrow.token,
+                timestamp: now,
             },
         );
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+    /// Pays an active stake back to its applicant (internal helper)
+    fn pay_back_stake(env: &Env, bounty_id: u64, applicant: Address) -> Result<(), Error> {
+        let mut stake = Self::active_stake(env, bounty_id, &applicant)?;
+        stake.status = StakeStatus::Returned;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stake(bounty_id, applicant.clone()), &stake);
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+        let client = token::Client::new(env, &stake.token);
+        client.transfer(&env.current_contract_address(), &applicant, &stake.amount);
+        Self::update_token_totals(env, &stake.token, -stake.amount, 0);
+
+        events::emit_stake_returned(
+            env,
+            events::StakeReturned {
+                bounty_id,
+                applicant,
+                amount: stake.amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
         Ok(())
     }
 
-    /// Releases escrowed funds to a contributor.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to release funds for
-    /// * `contributor` - Address to receive the funds
-    ///
-    /// # Returns
-    /// * `Ok(())` - Funds successfully released
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::Unauthorized)` - Caller is not the admin
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to contributor
-    /// - Updates escrow status to Released
-    /// - Emits FundsReleased event
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only admin can call this function
-    /// - Admin address must match initialization value
-    ///
-    /// # Security Considerations
-    /// - This is the most security-critical function
-    /// - Admin should verify task completion off-chain before calling
-    /// - Once released, funds cannot be retrieved
-    /// - Recipient address should be verified carefully
-    /// - Consider implementing multi-sig for admin
-    ///
-    /// # Events
-    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
-    ///
-    /// # Example
-    /// ```rust
-    /// // After verifying task completion off-chain:
-    /// let contributor = Address::from_string("GCONTRIB...");
-    ///
-    /// // Admin calls release
-    /// escrow_client.release_funds(&42, &contributor)?;
-    /// // Funds transferred to contributor, escrow marked as Released
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage update + event emission
-    ///
-    /// # Best Practices
-    /// 1. Verify contributor identity off-chain
-    /// 2. Confirm task completion before release
-    /// 3. Log release decisions in backend system
-    /// 4. Monitor release events for anomalies
-    /// 5. Consider implementing release delays for high-value bounties
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
-
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
+    /// Returns an applicant's stake, e.g. after the application was rejected
+    /// or the work was completed (admin only).
+    pub fn return_stake(env: Env, bounty_id: u64, applicant: Address) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::NotInitialized);
         }
 
-        // Verify admin authorization
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::ContractPaused);
-        }
+        Self::pay_back_stake(&env, bounty_id, applicant)
+    }
 
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+    /// Withdraws the caller's own stake once the bounty has been released,
+    /// refunded or cancelled (applicant must authorize), so a stake is never
+    /// stuck when the admin does not return it. While the bounty is still
+    /// open, disputed or streaming, a rejected applicant gets the stake back
+    /// through `return_stake`.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `BountyStillOpen` - The escrow has not reached a final state
+    /// * `StakeNotActive` - Applicant has no active stake on the bounty
+    pub fn withdraw_stake(env: Env, bounty_id: u64, applicant: Address) -> Result<(), Error> {
+        applicant.require_auth();
 
-        admin.require_auth();
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        match escrow.status {
+            EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Cancelled => {}
+            _ => return Err(Error::BountyStillOpen),
+        }
 
-        // Verify bounty exists
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        Self::pay_back_stake(&env, bounty_id, applicant)
+    }
+
+    /// Slashes an applicant's stake, e.g. after the applicant abandoned the
+    /// bounty (admin only).
+    ///
+    /// The stake goes to the bounty's refund address, or to the contract's
+    /// fee ledger when `target` is `Treasury`. Stakes can only be slashed
+    /// while the bounty is open; afterwards the applicant can withdraw them.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - The bounty is no longer open
+    /// * `StakeNotActive` - Applicant has no active stake on the bounty
+    pub fn slash_stake(
+        env: Env,
+        bounty_id: u64,
+        applicant: Address,
+        target: SlashTarget,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
-        // Get and verify escrow state
-        let mut escrow: Escrow = env
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
+            .ok_or(Error::BountyNotFound)?;
         if escrow.status != EscrowStatus::Locked {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::FundsNotLocked);
         }
 
-        // Transfer funds to contributor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        escrow.status = EscrowStatus::Released;
+        let mut stake = Self::active_stake(&env, bounty_id, &applicant)?;
+        stake.status = StakeStatus::Slashed;
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, fee_config.release_fee_rate)
-        } else {
-            0
+            .set(&DataKey::Stake(bounty_id, applicant.clone()), &stake);
+
+        let recipient = match target {
+            SlashTarget::Depositor => {
+                let refund_to = Self::refund_address_for(&env, bounty_id, &escrow);
+                let client = token::Client::new(&env, &stake.token);
+                client.transfer(&env.current_contract_address(), &refund_to, &stake.amount);
+                Self::update_token_totals(&env, &stake.token, -stake.amount, 0);
+                refund_to
+            }
+            SlashTarget::Treasury => {
+                Self::accrue_fee(&env, &stake.token, stake.amount);
+                Self::update_token_totals(&env, &stake.token, -stake.amount, stake.amount);
+                env.current_contract_address()
+            }
         };
-        let net_amount = escrow.amount - fee_amount;
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        events::emit_stake_slashed(
+            &env,
+            events::StakeSlashed {
+                bounty_id,
+                applicant,
+                amount: stake.amount,
+                recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_config.fee_recipient,
-                &fee_amount,
-            );
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Release,
-                    amount: fee_amount,
-                    fee_rate: fee_config.release_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
-        }
+        Ok(())
+    }
 
-        // Update escrow state - mark as released and set remaining_amount to 0
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
+    /// Get an applicant's stake on a bounty, in any state (view function)
+    pub fn get_application_stake(
+        env: Env,
+        bounty_id: u64,
+        applicant: Address,
+    ) -> Option<ApplicationStake> {
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .get(&DataKey::Stake(bounty_id, applicant))
+    }
 
-        // Emit release event
-        emit_funds_released(
-            &env,
-            FundsReleased {
+    /// Get every address that has staked on a bounty (view function)
+    pub fn get_applicants(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Applicants(bounty_id))
+            .unwrap_or(vec![&env])
+    }
+
+    // ========================================================================
+    // Completion Receipts
+    // ========================================================================
+
+    /// Records a payout on the contributor's receipt for a bounty, creating
+    /// the receipt on first payout (internal helper)
+    fn record_receipt(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+        token: &Address,
+        amount: i128,
+    ) {
+        let key = DataKey::Receipt(contributor.clone(), bounty_id);
+        let metadata_hash = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MetadataHash(bounty_id))
+            .unwrap_or(BytesN::from_array(env, &[0; 32]));
+        let timestamp = env.ledger().timestamp();
+
+        let receipt = match env.storage().persistent().get::<_, CompletionReceipt>(&key) {
+            Some(mut receipt) => {
+                receipt.amount += amount;
+                receipt.timestamp = timestamp;
+                receipt.metadata_hash = metadata_hash;
+                receipt
+            }
+            None => {
+                let index_key = DataKey::ContributorReceipts(contributor.clone());
+                let mut bounty_ids: Vec<u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&index_key)
+                    .unwrap_or(vec![env]);
+                bounty_ids.push_back(bounty_id);
+                env.storage().persistent().set(&index_key, &bounty_ids);
+
+                CompletionReceipt {
+                    bounty_id,
+                    contributor: contributor.clone(),
+                    amount,
+                    token: token.clone(),
+                    timestamp,
+                    metadata_hash,
+                }
+            }
+        };
+        env.storage().persistent().set(&key, &receipt);
+
+        events::emit_receipt_recorded(
+            env,
+            events::ReceiptRecorded {
                 bounty_id,
-                amount: net_amount, // Emit net amount (after fee)
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
+                contributor: contributor.clone(),
+                amount,
+                total: receipt.amount,
+                token: token.clone(),
+                timestamp,
             },
         );
+    }
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+    /// Sets the hash of a bounty's off-chain metadata (depositor only).
+    /// Receipts issued after this call carry the hash.
+    pub fn set_metadata_hash(env: Env, bounty_id: u64, hash: BytesN<32>) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+        escrow.depositor.require_auth();
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("release"), duration);
+        env.storage()
+            .persistent()
+            .set(&DataKey::MetadataHash(bounty_id), &hash);
         Ok(())
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
+    /// Get a bounty's metadata hash, if set (view function)
+    pub fn get_metadata_hash(env: Env, bounty_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MetadataHash(bounty_id))
+    }
+
+    /// Get a contributor's receipt for a bounty (view function)
+    pub fn get_receipt(
         env: Env,
+        contributor: Address,
         bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    ) -> Option<CompletionReceipt> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Receipt(contributor, bounty_id))
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+    /// Get the number of receipts a contributor holds (view function)
+    pub fn get_receipt_count(env: Env, contributor: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, Vec<u64>>(&DataKey::ContributorReceipts(contributor))
+            .map(|ids| ids.len())
+            .unwrap_or(0)
+    }
 
-        let escrow: Escrow = env
+    /// Get a page of a contributor's receipts, oldest first (view function)
+    ///
+    /// # Arguments
+    /// * `contributor` - Receipt holder
+    /// * `offset` - Number of receipts to skip
+    /// * `limit` - Maximum number of receipts to return
+    pub fn get_receipts(
+        env: Env,
+        contributor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<CompletionReceipt> {
+        let bounty_ids: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
+            .get(&DataKey::ContributorReceipts(contributor.clone()))
+            .unwrap_or(vec![&env]);
+
+        let mut receipts = vec![&env];
+        let end = offset.saturating_add(limit).min(bounty_ids.len());
+        for i in offset..end {
+            let bounty_id = bounty_ids.get(i).unwrap();
+            if let Some(receipt) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Receipt(contributor.clone(), bounty_id))
+            {
+                receipts.push_back(receipt);
+            }
         }
+        receipts
+    }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+    // ========================================================================
+    // Address Ledgers
+    // ========================================================================
+
+    /// Adds a payout to a contributor's totals for a token (internal helper)
+    fn update_contributor_stats(
+        env: &Env,
+        contributor: &Address,
+        token: &Address,
+        earned: i128,
+        fees_paid: i128,
+    ) {
+        let key = DataKey::ContributorStats(contributor.clone());
+        let mut all: Vec<ContributorStats> =
+            env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        let index = all.iter().position(|stats| stats.token == *token);
+        let mut stats = match index {
+            Some(i) => all.get(i as u32).unwrap(),
+            None => ContributorStats {
+                token: token.clone(),
+                earned: 0,
+                fees_paid: 0,
+                payouts: 0,
+            },
+        };
+        stats.earned += earned;
+        stats.fees_paid += fees_paid;
+        stats.payouts += 1;
+        match index {
+            Some(i) => all.set(i as u32, stats),
+            None => all.push_back(stats),
         }
+        env.storage().persistent().set(&key, &all);
+    }
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
+    /// Adds to a depositor's totals for a token (internal helper)
+    fn update_depositor_stats(
+        env: &Env,
+        depositor: &Address,
+        token: &Address,
+        funded: i128,
+        refunded: i128,
+        fees_paid: i128,
+    ) {
+        let key = DataKey::DepositorStats(depositor.clone());
+        let mut all: Vec<DepositorStats> =
+            env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        let index = all.iter().position(|stats| stats.token == *token);
+        let mut stats = match index {
+            Some(i) => all.get(i as u32).unwrap(),
+            None => DepositorStats {
+                token: token.clone(),
+                funded: 0,
+                refunded: 0,
+                fees_paid: 0,
+            },
         };
+        stats.funded += funded;
+        stats.refunded += refunded;
+        stats.fees_paid += fees_paid;
+        match index {
+            Some(i) => all.set(i as u32, stats),
+            None => all.push_back(stats),
+        }
+        env.storage().persistent().set(&key, &all);
+    }
 
+    /// Get a contributor's earnings, one entry per token received (view function)
+    pub fn get_contributor_stats(env: Env, contributor: Address) -> Vec<ContributorStats> {
         env.storage()
             .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+            .get(&DataKey::ContributorStats(contributor))
+            .unwrap_or(vec![&env])
+    }
 
-        Ok(())
+    /// Get a depositor's funding totals, one entry per token locked (view function)
+    pub fn get_depositor_stats(env: Env, depositor: Address) -> Vec<DepositorStats> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DepositorStats(depositor))
+            .unwrap_or(vec![&env])
     }
 
-    /// Refund funds with support for Full, Partial, and Custom refunds.
-    /// - Full: refunds all remaining funds to depositor
-    /// - Partial: refunds specified amount to depositor
-    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
-    pub fn refund(
+    // ========================================================================
+    // Escrow Ownership
+    // ========================================================================
+
+    /// Returns where Full/Partial refunds for a bounty are sent (internal helper).
+    fn refund_address_for(env: &Env, bounty_id: u64, escrow: &Escrow) -> Address {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundTo(bounty_id))
+            .unwrap_or_else(|| escrow.depositor.clone())
+    }
+
+    /// Transfers ownership of an escrow to a new depositor.
+    ///
+    /// The new depositor takes over every depositor right on the bounty
+    /// (refunds, cancellation, challenging releases). Any refund address set
+    /// by the previous owner is cleared.
+    ///
+    /// # Authorization
+    /// - Current depositor must authorize the transaction
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - Bounty doesn't exist
+    /// * `FundsNotLocked` - Escrow is already released, refunded or cancelled
+    /// * `AddressDenylisted` - New depositor is on the denylist
+    /// * `DepositorCapExceeded` - The escrow would put the new depositor over
+    ///   the per-depositor cap
+    ///
+    /// # Events
+    /// Emits: `EscrowOwnershipTransferred { bounty_id, previous_depositor, new_depositor, timestamp }`
+    pub fn transfer_escrow_ownership(
         env: Env,
         bounty_id: u64,
-        amount: Option<i128>,
-        recipient: Option<Address>,
-        mode: RefundMode,
+        new_depositor: Address,
     ) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
-
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            return Err(Error::ContractPaused);
-        }
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
-        }
-
-        // Get and verify escrow state
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        let caller = escrow.depositor.clone();
+            .ok_or(Error::BountyNotFound)?;
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        let previous_depositor = escrow.depositor.clone();
+        previous_depositor.require_auth();
+
+        if escrow.status == EscrowStatus::Released
+            || escrow.status == EscrowStatus::Refunded
+            || escrow.status == EscrowStatus::Cancelled
         {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_not_denylisted(&env, &new_depositor)?;
+        Self::check_depositor_cap(&env, &new_depositor, &escrow.token, escrow.remaining_amount)?;
 
-        // Verify deadline has passed
-        let now = env.ledger().timestamp();
-        let is_before_deadline = now < escrow.deadline;
-
-        // Determine refund amount and recipient
-        let refund_amount: i128;
-        let refund_recipient: Address;
-
-        match mode {
-            RefundMode::Full => {
-                refund_amount = escrow.remaining_amount;
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Partial => {
-                refund_amount = amount.unwrap_or(escrow.remaining_amount);
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Custom => {
-                refund_amount = amount.ok_or(Error::InvalidAmount)?;
-                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
-
-                // Custom refunds before deadline require admin approval
-                if is_before_deadline {
-                    if !env
-                        .storage()
-                        .persistent()
-                        .has(&DataKey::RefundApproval(bounty_id))
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-                    let approval: RefundApproval = env
-                        .storage()
-                        .persistent()
-                        .get(&DataKey::RefundApproval(bounty_id))
-                        .unwrap();
-
-                    // Verify approval matches request
-                    if approval.amount != refund_amount
-                        || approval.recipient != refund_recipient
-                        || approval.mode != mode
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-
-                    // Clear approval after use
-                    env.storage()
-                        .persistent()
-                        .remove(&DataKey::RefundApproval(bounty_id));
-                }
-            }
-        }
-
-        // Validate amount
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Transfer funds back to depositor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Check contract balance
-        let contract_balance = client.balance(&env.current_contract_address());
-        if contract_balance < refund_amount {
-            return Err(Error::InsufficientFunds);
-        }
-
-        // Transfer funds
-        client.transfer(
-            &env.current_contract_address(),
-            &refund_recipient,
-            &refund_amount,
+        // Move the locked amount over to the new owner
+        Self::adjust_depositor_locked(
+            &env,
+            &previous_depositor,
+            &escrow.token,
+            -escrow.remaining_amount,
         );
+        Self::adjust_depositor_locked(&env, &new_depositor, &escrow.token, escrow.remaining_amount);
 
-        // Update escrow state
-        escrow.remaining_amount -= refund_amount;
-
-        // Add to refund history
-        let refund_record = RefundRecord {
-            amount: refund_amount,
-            recipient: refund_recipient.clone(),
-            mode: mode.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        escrow.refund_history.push_back(refund_record);
-
-        // Update status
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
-
+        escrow.depositor = new_depositor.clone();
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RefundTo(bounty_id));
 
-        // Emit refund event
-        emit_funds_refunded(
+        events::emit_escrow_ownership_transferred(
             &env,
-            FundsRefunded {
+            events::EscrowOwnershipTransferred {
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_recipient,
+                previous_depositor,
+                new_depositor,
                 timestamp: env.ledger().timestamp(),
-                refund_mode: mode.clone(),
-                remaining_amount: escrow.remaining_amount,
             },
         );
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+    /// Sets (or clears, with `None`) the address that receives Full and
+    /// Partial refunds for a bounty. Custom refunds keep their explicit recipient.
+    ///
+    /// # Authorization
+    /// - Depositor must authorize the transaction
+    ///
+    /// # Events
+    /// Emits: `RefundAddressUpdated { bounty_id, refund_to, timestamp }`
+    pub fn set_refund_address(
+        env: Env,
+        bounty_id: u64,
+        refund_to: Option<Address>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+        escrow.depositor.require_auth();
+
+        match refund_to.clone() {
+            Some(addr) => env
+                .storage()
+                .persistent()
+                .set(&DataKey::RefundTo(bounty_id), &addr),
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::RefundTo(bounty_id)),
+        }
+
+        events::emit_refund_address_updated(
+            &env,
+            events::RefundAddressUpdated {
+                bounty_id,
+                refund_to,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
         Ok(())
     }
 
+    /// Returns the address that Full/Partial refunds for a bounty go to (view function).
+    pub fn get_refund_address(env: Env, bounty_id: u64) -> Result<Address, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        Ok(Self::refund_address_for(&env, bounty_id, &escrow))
+    }
+
     // ========================================================================
     // View Functions (Read-only)
     // ========================================================================
@@ -1488,37 +5385,58 @@ impl BountyEscrowContract {
     /// * `bounty_id` - The bounty to query
     ///
     /// # Returns
-    /// * `Ok(Escrow)` - The complete escrow record
+    /// * `Ok(EscrowInfo)` - The complete escrow record and its current payable amount
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
     ///
     /// # Gas Cost
-    /// Very Low - Single storage read
+    /// Very Low - Two storage reads
     ///
     /// # Example
     /// ```rust
     /// let escrow_info = escrow_client.get_escrow_info(&42)?;
     /// println!("Amount: {}", escrow_info.amount);
+    /// println!("Payable now: {}", escrow_info.payable_amount);
     /// println!("Status: {:?}", escrow_info.status);
     /// println!("Deadline: {}", escrow_info.deadline);
     /// ```
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<EscrowInfo, Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        Ok(env
+        let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+            .unwrap();
+
+        let payable_amount =
+            if escrow.status == EscrowStatus::Locked || escrow.status == EscrowStatus::Disputed {
+                Self::payable_amount_internal(&env, bounty_id, &escrow)
+            } else {
+                0
+            };
+        Ok(EscrowInfo {
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            status: escrow.status,
+            deadline: escrow.deadline,
+            refund_history: escrow.refund_history,
+            remaining_amount: escrow.remaining_amount,
+            token: escrow.token,
+            initiator: escrow.initiator,
+            frozen: escrow.frozen,
+            payable_amount,
+        })
     }
 
-    /// Returns the current token balance held by the contract.
+    /// Returns the contract's current balance of `token`.
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `token` - Token contract to query
     ///
     /// # Returns
-    /// * `Ok(i128)` - Current contract token balance
+    /// * `Ok(i128)` - Current contract balance of `token`
     /// * `Err(Error::NotInitialized)` - Contract not initialized
     ///
     /// # Use Cases
@@ -1531,18 +5449,31 @@ impl BountyEscrowContract {
     ///
     /// # Example
     /// ```rust
-    /// let balance = escrow_client.get_balance()?;
+    /// let balance = escrow_client.get_balance(&usdc)?;
     /// println!("Total locked: {} stroops", balance);
     /// ```
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
+    pub fn get_balance(env: Env, token: Address) -> Result<i128, Error> {
         if !env.storage().instance().has(&DataKey::Token) {
             return Err(Error::NotInitialized);
         }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        let client = token::Client::new(&env, &token);
         Ok(client.balance(&env.current_contract_address()))
     }
 
+    /// Returns the running totals for `token` (view function).
+    ///
+    /// Compare `locked` plus `get_accrued_fees(token)` against
+    /// `get_balance(token)` to verify solvency.
+    pub fn get_token_totals(env: Env, token: Address) -> TokenTotals {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenTotals(token))
+            .unwrap_or(TokenTotals {
+                locked: 0,
+                fees_collected: 0,
+            })
+    }
+
     /// Retrieves the refund history for a specific bounty.
     ///
     /// # Arguments
@@ -1571,16 +5502,17 @@ impl BountyEscrowContract {
     /// * `bounty_id` - The bounty to query
     ///
     /// # Returns
-    /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
+    /// * `Ok((bool, bool, i128, Option<RefundApproval>, bool))` - Tuple containing:
     ///   - can_refund: Whether refund is possible
     ///   - deadline_passed: Whether the deadline has passed
     ///   - remaining: Remaining amount in escrow
     ///   - approval: Optional refund approval if exists
+    ///   - frozen: Whether the bounty is under a compliance freeze
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
     pub fn get_refund_eligibility(
         env: Env,
         bounty_id: u64,
-    ) -> Result<(bool, bool, i128, Option<RefundApproval>), Error> {
+    ) -> Result<(bool, bool, i128, Option<RefundApproval>, bool), Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
@@ -1610,16 +5542,19 @@ impl BountyEscrowContract {
 
         // can_refund is true if:
         // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
+        // 2. (deadline has passed OR there's an approval) AND
+        // 3. The bounty is not frozen
         let can_refund = (escrow.status == EscrowStatus::Locked
             || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+            && (deadline_passed || approval.is_some())
+            && !escrow.frozen;
 
         Ok((
             can_refund,
             deadline_passed,
             escrow.remaining_amount,
             approval,
+            escrow.frozen,
         ))
     }
 
@@ -1627,7 +5562,7 @@ impl BountyEscrowContract {
     /// This improves gas efficiency by reducing transaction overhead.
     ///
     /// # Arguments
-    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, and deadline
+    /// * `items` - Vector of LockFundsItem containing bounty_id, depositor, amount, deadline and token
     ///
     /// # Returns
     /// Number of successfully locked bounties
@@ -1635,6 +5570,9 @@ impl BountyEscrowContract {
     /// # Errors
     /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
     /// * BountyExists - if any bounty_id already exists
+    /// * TokenNotAccepted - if any item uses a token that is not on the allowlist
+    /// * InvalidAmount / InvalidDeadline - if any item violates the lock policy
+    /// * DepositorCapExceeded - if the batch takes a depositor past its cap
     /// * NotInitialized - if contract is not initialized
     ///
     /// # Note
@@ -1658,13 +5596,15 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
         let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
         // Validate all items before processing (all-or-nothing approach)
         for item in items.iter() {
+            if !Self::is_token_accepted_internal(&env, &item.token) {
+                return Err(Error::TokenNotAccepted);
+            }
+
             // Check if bounty already exists
             if env
                 .storage()
@@ -1674,10 +5614,12 @@ impl BountyEscrowContract {
                 return Err(Error::BountyExists);
             }
 
-            // Validate amount
-            if item.amount <= 0 {
-                return Err(Error::InvalidAmount);
-            }
+            Self::ensure_not_denylisted(&env, &item.depositor)?;
+
+            // Validate amount and deadline against the lock policy; the
+            // per-depositor cap is checked as items are processed
+            Self::check_amount_policy(&env, item.amount)?;
+            Self::check_deadline_policy(&env, item.deadline)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -1711,8 +5653,14 @@ impl BountyEscrowContract {
         // Process all items (atomic - all succeed or all fail)
         let mut locked_count = 0u32;
         for item in items.iter() {
+            Self::check_depositor_cap(&env, &item.depositor, &item.token, item.amount)?;
+
             // Transfer funds from depositor to contract
+            let client = token::Client::new(&env, &item.token);
             client.transfer(&item.depositor, &contract_address, &item.amount);
+            Self::update_token_totals(&env, &item.token, item.amount, 0);
+            Self::adjust_depositor_locked(&env, &item.depositor, &item.token, item.amount);
+            Self::update_depositor_stats(&env, &item.depositor, &item.token, item.amount, 0, 0);
 
             // Create escrow record
             let escrow = Escrow {
@@ -1722,6 +5670,9 @@ impl BountyEscrowContract {
                 deadline: item.deadline,
                 refund_history: vec![&env],
                 remaining_amount: item.amount,
+                token: item.token.clone(),
+                initiator: item.depositor.clone(),
+                frozen: false,
             };
 
             // Store escrow
@@ -1737,6 +5688,8 @@ impl BountyEscrowContract {
                     amount: item.amount,
                     depositor: item.depositor.clone(),
                     deadline: item.deadline,
+                    token: item.token.clone(),
+                    initiator: item.depositor.clone(),
                 },
             );
 
@@ -1795,8 +5748,6 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
         let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
@@ -1822,6 +5773,36 @@ impl BountyEscrowContract {
             if escrow.status != EscrowStatus::Locked {
                 return Err(Error::FundsNotLocked);
             }
+            if escrow.frozen {
+                return Err(Error::BountyFrozen);
+            }
+            Self::ensure_not_denylisted(&env, &item.contributor)?;
+            Self::ensure_attested(
+                &env,
+                &item.contributor,
+                Self::payable_amount_internal(&env, item.bounty_id, &escrow),
+            )?;
+
+            // Contest bounties are paid out through finalize_contest
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Contest(item.bounty_id))
+            {
+                return Err(Error::ContestBounty);
+            }
+
+            // High-value bounties must go through the delayed release flow
+            if Self::challenge_period_for(&env, item.bounty_id, &escrow) > 0 {
+                return Err(Error::DelayedReleaseRequired);
+            }
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::PendingRelease(item.bounty_id))
+            {
+                return Err(Error::ReleasePending);
+            }
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -1848,11 +5829,29 @@ impl BountyEscrowContract {
                 .get(&DataKey::Escrow(item.bounty_id))
                 .unwrap();
 
-            // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+            // Transfer funds, including any accrued boost, to contributor
+            let payout = escrow.amount + Self::settle_boost(&env, item.bounty_id, &escrow);
+            let client = token::Client::new(&env, &escrow.token);
+            client.transfer(&contract_address, &item.contributor, &payout);
+            Self::record_receipt(
+                &env,
+                item.bounty_id,
+                &item.contributor,
+                &escrow.token,
+                payout,
+            );
+            Self::update_contributor_stats(&env, &item.contributor, &escrow.token, payout, 0);
+            Self::update_token_totals(&env, &escrow.token, -payout, 0);
+            Self::adjust_depositor_locked(
+                &env,
+                &escrow.depositor,
+                &escrow.token,
+                -escrow.remaining_amount,
+            );
 
             // Update escrow status
             escrow.status = EscrowStatus::Released;
+            escrow.remaining_amount = 0;
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
@@ -1862,9 +5861,10 @@ impl BountyEscrowContract {
                 &env,
                 FundsReleased {
                     bounty_id: item.bounty_id,
-                    amount: escrow.amount,
+                    amount: payout,
                     recipient: item.contributor.clone(),
                     timestamp,
+                    token: escrow.token.clone(),
                 },
             );
 
@@ -48,7 +48,7 @@ fn setup_bounty_with_schedule(
 
     // Lock funds for bounty
     token_client.approve(admin, contract_id, &amount, &1000);
-    client.lock_funds(&contributor.clone(), &bounty_id, &amount, &1000000000);
+    client.lock_funds(&contributor.clone(), &bounty_id, &amount, &1000000000, &None);
 
     // Create release schedule
     client.create_release_schedule(
@@ -91,7 +91,7 @@ fn test_single_release_schedule() {
     let deadline = env.ledger().timestamp() + 1000000000;
 
     // Lock funds
-    escrow.lock_funds(&admin, &bounty_id, &amount, &deadline);
+    escrow.lock_funds(&admin, &bounty_id, &amount, &deadline, &None);
 
     // Create release schedule
     let release_timestamp = 1000;
@@ -150,7 +150,7 @@ fn test_multiple_release_schedules() {
     let deadline = env.ledger().timestamp() + 1000000000;
 
     // Lock funds
-    escrow.lock_funds(&admin, &bounty_id, &total_amount, &deadline);
+    escrow.lock_funds(&admin, &bounty_id, &total_amount, &deadline, &None);
 
     // Create first release schedule
     escrow.create_release_schedule(&bounty_id, &amount1, &1000, &contributor1.clone());
@@ -234,7 +234,9 @@ fn test_lock_fund() {
 
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(
+        &depositor, &bounty_id, &amount, &deadline, &token, &None, &None,
+    );
 
     // Get all events emitted
     let events = env.events().all();
@@ -266,15 +268,18 @@ fn test_release_fund() {
 
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(
+        &depositor, &bounty_id, &amount, &deadline, &token, &None, &None,
+    );
 
-    client.release_funds(&bounty_id, &contributor);
+    client.release_funds(&bounty_id, &contributor, &None);
 
     // Get all events emitted
     let events = env.events().all();
 
-    // Verify the event was emitted (7 original events + 6 monitoring events from init, lock_funds & release_funds)
-    assert_eq!(events.len(), 13);
+    // Verify the event was emitted (7 original events + 6 monitoring events from init, lock_funds & release_funds
+    // + 1 receipt event)
+    assert_eq!(events.len(), 14);
 }
 
 #[test]
@@ -294,7 +299,9 @@ fn test_lock_fund_invalid_amount() {
 
     client.init(&admin.clone(), &token.clone());
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(
+        &depositor, &bounty_id, &amount, &deadline, &token, &None, &None,
+    );
 }
 
 #[test]
@@ -315,7 +322,9 @@ fn test_lock_fund_invalid_deadline() {
     client.init(&admin.clone(), &token.clone());
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(
+        &depositor, &bounty_id, &amount, &deadline, &token, &None, &None,
+    );
 }
 
 // ============================================================================
@@ -345,18 +354,21 @@ fn test_batch_lock_funds() {
         depositor: depositor.clone(),
         amount: 1000,
         deadline: 100,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 2,
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 3,
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 300,
+        token: token.clone(),
     });
 
     // Execute batch lock
@@ -392,8 +404,8 @@ fn test_batch_release_funds() {
     let amount2 = 2000i128;
     token_admin_client.mint(&depositor, &(amount1 + amount2));
 
-    client.lock_funds(&depositor, &1, &amount1, &100);
-    client.lock_funds(&depositor, &2, &amount2, &200);
+    client.lock_funds(&depositor, &1, &amount1, &100, &token, &None, &None);
+    client.lock_funds(&depositor, &2, &amount2, &200, &token, &None, &None);
 
     // Create batch release items
     let mut items = vec![&env];
@@ -443,12 +455,14 @@ fn test_batch_lock_duplicate_bounty_id() {
         depositor: depositor.clone(),
         amount: 1000,
         deadline: 100,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 1, // Duplicate!
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
 
     client.batch_lock_funds(&items);
@@ -469,7 +483,7 @@ fn test_batch_lock_existing_bounty() {
     token_admin_client.mint(&depositor, &5000);
 
     // Lock a bounty first
-    client.lock_funds(&depositor, &1, &1000, &100);
+    client.lock_funds(&depositor, &1, &1000, &100, &token, &None, &None);
 
     // Try to batch lock the same bounty
     let mut items = vec![&env];
@@ -478,6 +492,7 @@ fn test_batch_lock_existing_bounty() {
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
 
     client.batch_lock_funds(&items);
@@ -509,12 +524,14 @@ fn test_batch_lock_event_emission() {
         depositor: depositor.clone(),
         amount: 1000,
         deadline: 100,
+        token: token.clone(),
     });
     items.push_back(crate::LockFundsItem {
         bounty_id: 2,
         depositor: depositor.clone(),
         amount: 2000,
         deadline: 200,
+        token: token.clone(),
     });
 
     client.batch_lock_funds(&items);
@@ -540,8 +557,8 @@ fn test_batch_release_event_emission() {
     token_admin_client.mint(&depositor, &5000);
 
     // Lock funds
-    client.lock_funds(&depositor, &1, &1000, &100);
-    client.lock_funds(&depositor, &2, &2000, &200);
+    client.lock_funds(&depositor, &1, &1000, &100, &token, &None, &None);
+    client.lock_funds(&depositor, &2, &2000, &200, &token, &None, &None);
 
     let initial_event_count = env.events().all().len();
 
@@ -588,7 +605,9 @@ fn test_complete_bounty_workflow_lock_release() {
     // 3. Lock funds
     let bounty_id = 1u64;
     let deadline = 1000u64;
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(
+        &depositor, &bounty_id, &amount, &deadline, &token, &None, &None,
+    );
 
     // 4. Verify funds locked
     let escrow = client.get_escrow_info(&bounty_id);
@@ -596,11 +615,11 @@ fn test_complete_bounty_workflow_lock_release() {
     assert_eq!(escrow.status, crate::EscrowStatus::Locked);
 
     // 5. Verify contract balance
-    let contract_balance = client.get_balance();
+    let contract_balance = client.get_balance(&token);
     assert_eq!(contract_balance, amount);
 
     // 6. Release funds to contributor
-    client.release_funds(&bounty_id, &contributor);
+    client.release_funds(&bounty_id, &contributor, &None);
 
     // 7. Verify funds released
     let escrow_after = client.get_escrow_info(&bounty_id);
@@ -630,7 +649,9 @@ fn test_complete_bounty_workflow_lock_refund() {
     // Use a future deadline, then advance the ledger timestamp past it
     let current_time = env.ledger().timestamp();
     let deadline = current_time + 1_000;
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(
+        &depositor, &bounty_id, &amount, &deadline, &token, &None, &None,
+    );
 
     // Advance time past deadline so refund is eligible
     env.ledger().set_timestamp(deadline + 1);
@@ -641,6 +662,7 @@ fn test_complete_bounty_workflow_lock_refund() {
         &None::<i128>,
         &None::<Address>,
         &crate::RefundMode::Full,
+        &None,
     );
 
     // Verify funds refunded
@@ -709,7 +731,7 @@ fn test_emergency_withdraw() {
     // Call emergency_withdraw (it will fail gracefully if no funds)
     // The important thing is that it's callable when paused
     let emergency_recipient = Address::generate(&env);
-    client.emergency_withdraw(&emergency_recipient);
+    client.emergency_withdraw(&token_address, &emergency_recipient);
 
     // Verify pause state still true
     assert_eq!(client.is_paused(), true);
@@ -49,7 +49,7 @@ fn test_pause_functionality() {
     let deadline = env.ledger().timestamp() + 1000;
 
     // This should fail with ContractPaused error
-    let result = client.try_lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    let result = client.try_lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
     assert!(result.is_err());
 
     // Unpause the contract
@@ -60,7 +60,7 @@ fn test_pause_functionality() {
 
     // Mint tokens to depositor and lock funds
     token_client.mint(&depositor, &amount);
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 }
 
 #[test]
@@ -75,7 +75,7 @@ fn test_emergency_withdraw() {
 
     // Mint tokens and lock funds
     token_client.mint(&depositor, &amount);
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
     // Pause and emergency withdraw
     client.pause(&Some(String::from_str(&env, "Emergency")));
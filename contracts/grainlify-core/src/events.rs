@@ -0,0 +1,96 @@
+//! # Grainlify Core Events Module
+//!
+//! Every event's data is a `(EventEnvelope, payload)` pair. The envelope
+//! layout is shared with the escrow contracts and is documented in
+//! `backend/EVENT_INDEXING_STRATEGY.md`; this contract's kind is `core`.
+
+use soroban_sdk::{contracttype, symbol_short, Env, IntoVal, String, Symbol, Topics, Val};
+
+/// Kind reported in the envelope of this contract's events.
+const CONTRACT_KIND: Symbol = symbol_short!("core");
+
+// ============================================================================
+// Event Envelope
+// ============================================================================
+//
+// This section is kept word-for-word identical in the bounty escrow, program
+// escrow and core `events.rs`; only `CONTRACT_KIND` above it differs. The
+// layout is documented in `backend/EVENT_INDEXING_STRATEGY.md`.
+
+/// Version of the envelope and payload layout, shared by every Grainlify
+/// contract. Bump it when any contract's payload changes shape.
+///
+/// * `1` - Initial envelope.
+/// * `2` - Program escrow `FundLock` payload gained the `funder` address.
+/// * `3` - Program escrow `pause`/`unpause` payloads became
+///   `(admin, timestamp)`.
+/// * `4` - Bounty escrow `FeeCollected` and `FeeConfigUpdated` no longer
+///   carry a fee recipient; fees go to `splits` or the contract's fee ledger.
+pub const EVENT_SCHEMA_VERSION: u32 = 4;
+
+/// Metadata wrapped around every event payload: events are published with
+/// `(EventEnvelope, payload)` as their data.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventEnvelope {
+    pub schema_version: u32,
+    pub contract_kind: Symbol,
+    pub sequence: u64,
+    pub correlation_id: Option<String>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum EnvelopeKey {
+    Sequence,
+    CorrelationId,
+}
+
+/// Returns the sequence number of the last event emitted, or zero.
+pub fn get_sequence(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&EnvelopeKey::Sequence)
+        .unwrap_or(0)
+}
+
+/// Runs `f` with `correlation_id` attached to every event it publishes.
+///
+/// The id is held in temporary storage only while `f` runs, so it never
+/// leaks into the events of a later, unrelated call.
+pub fn with_correlation_id<R>(
+    env: &Env,
+    correlation_id: Option<String>,
+    f: impl FnOnce() -> R,
+) -> R {
+    if let Some(id) = correlation_id {
+        env.storage()
+            .temporary()
+            .set(&EnvelopeKey::CorrelationId, &id);
+    }
+    let result = f();
+    env.storage()
+        .temporary()
+        .remove(&EnvelopeKey::CorrelationId);
+    result
+}
+
+/// Publishes `data` wrapped in the next event envelope.
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    let sequence = get_sequence(env) + 1;
+    env.storage()
+        .instance()
+        .set(&EnvelopeKey::Sequence, &sequence);
+
+    let envelope = EventEnvelope {
+        schema_version: EVENT_SCHEMA_VERSION,
+        contract_kind: CONTRACT_KIND,
+        sequence,
+        correlation_id: env.storage().temporary().get(&EnvelopeKey::CorrelationId),
+    };
+    env.events().publish(topics, (envelope, data.into_val(env)));
+}
@@ -118,7 +118,8 @@ impl GovernanceContract {
         env.storage().instance().set(&PROPOSAL_COUNT, &0u32);
         
         // Emit event
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("gov_init"), admin.clone()),
             config,
         );
@@ -191,7 +192,8 @@ impl GovernanceContract {
             .set(&PROPOSAL_COUNT, &(proposal_id + 1));
         
         // Emit event
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("proposal"), proposer.clone()),
             (proposal_id, description),
         );
@@ -295,7 +297,8 @@ impl GovernanceContract {
         env.storage().instance().set(&PROPOSALS, &proposals);
         
         // Emit event
-        env.events().publish(
+        crate::events::publish(
+            &env,
             (symbol_short!("vote"), voter.clone()),
             (proposal_id, vote_type),
         );
@@ -375,7 +378,8 @@ impl GovernanceContract {
         env.storage().instance().set(&PROPOSALS, &proposals);
         
         // Emit event
-        env.events().publish(
+        crate::events::publish(
+            &env,
             (symbol_short!("finalize"), proposal_id),
             proposal.status.clone(),
         );
@@ -434,7 +438,8 @@ impl GovernanceContract {
         env.storage().instance().set(&PROPOSALS, &proposals);
         
         // Emit event
-        env.events().publish(
+        crate::events::publish(
+            &env,
             (symbol_short!("execute"), executor.clone()),
             proposal_id,
         );
@@ -168,6 +168,12 @@ use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec, String,
 };
 
+// ==================== EVENTS MODULE ====================
+// Core operations take no correlation id, so `with_correlation_id` from the
+// shared envelope section is unused here.
+#[allow(dead_code)]
+mod events;
+
 // ==================== MONITORING MODULE ====================
 mod monitoring {
     use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
@@ -249,7 +255,8 @@ mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
                 operation,
@@ -273,7 +280,8 @@ mod monitoring {
             .persistent()
             .set(&time_key, &(total + duration));
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
                 function,
@@ -782,6 +790,15 @@ impl GrainlifyContract {
     }
 
 
+    // ========================================================================
+    // Event Envelope
+    // ========================================================================
+
+    /// Gets the sequence number of the last event emitted.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        events::get_sequence(&env)
+    }
+
     // ========================================================================
     // Version Management
     // ========================================================================
@@ -1154,7 +1171,8 @@ impl GrainlifyContract {
 
 /// Emits a migration event for audit trail
 fn emit_migration_event(env: &Env, event: MigrationEvent) {
-    env.events().publish(
+    events::publish(
+        env,
         (symbol_short!("migration"),),
         event,
     );
@@ -1405,5 +1423,30 @@ mod internal_test {
         let events = env.events().all();
         assert!(events.len() > initial_event_count);
     }
-}
 
+    #[test]
+    fn test_events_carry_envelope() {
+        use soroban_sdk::{TryFromVal, Val};
+
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GrainlifyContract);
+        let client = GrainlifyContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init_admin(&admin);
+
+        // init_admin starts the contract at the current version
+        let migration_hash = BytesN::from_array(&env, &[3u8; 32]);
+        client.migrate(&3, &migration_hash);
+
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (envelope, _payload) =
+            <(events::EventEnvelope, Val)>::try_from_val(&env, &data).unwrap();
+        assert_eq!(envelope.schema_version, events::EVENT_SCHEMA_VERSION);
+        assert_eq!(envelope.contract_kind, symbol_short!("core"));
+        assert_eq!(envelope.correlation_id, None);
+        assert_eq!(envelope.sequence, client.get_event_sequence());
+    }
+}
@@ -92,7 +92,8 @@ impl MultiSig {
             .instance()
             .set(&DataKey::ProposalCounter, &counter);
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("proposal"),),
             counter,
         );
@@ -123,7 +124,8 @@ impl MultiSig {
             .instance()
             .set(&DataKey::Proposal(proposal_id), &proposal);
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("approved"),),
             (proposal_id, signer),
         );
@@ -155,7 +157,8 @@ impl MultiSig {
             .instance()
             .set(&DataKey::Proposal(proposal_id), &proposal);
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("executed"),),
             proposal_id,
         );
@@ -99,9 +99,9 @@
 //!     &usdc_token
 //! );
 //!
-//! // 2. Lock prize pool (10,000 USDC)
+//! // 2. Lock prize pool (10,000 USDC), pulled from the organizer
 //! let prize_pool = 10_000_0000000; // 10,000 USDC (7 decimals)
-//! escrow_client.lock_program_funds(&prize_pool);
+//! escrow_client.lock_program_funds(&program_id, &organizer, &prize_pool);
 //!
 //! // 3. After hackathon, distribute prizes
 //! let winners = vec![
@@ -140,8 +140,8 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, String, Symbol,
-    Vec,
+    contract, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN, Env, String,
+    Symbol, Vec,
 };
 
 // Event types
@@ -152,7 +152,6 @@ const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
 
 // Storage keys
-const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
 
 // Fee rate is stored in basis points (1 basis point = 0.01%)
@@ -160,14 +159,53 @@ const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+// Current contract version, stored on first program registration.
+// There is no `migrate` yet: add one together with the first version whose
+// storage layout differs, instead of bumping this alone.
+// - v1: Initial upgradeable release
+const VERSION: u32 = 1;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
-    pub lock_fee_rate: i128,    // Fee rate for lock operations (basis points)
-    pub payout_fee_rate: i128,  // Fee rate for payout operations (basis points)
-    pub fee_recipient: Address, // Address to receive fees
-    pub fee_enabled: bool,      // Global fee enable/disable flag
+    pub lock_fee_rate: i128,       // Fee rate for lock operations (basis points)
+    pub payout_fee_rate: i128,     // Fee rate for payout operations (basis points)
+    pub fee_recipient: Address,    // Address to receive fees
+    pub fee_enabled: bool,         // Global fee enable/disable flag
+    pub fee_splits: Vec<FeeSplit>, // Weighted recipients; empty = all to fee_recipient
+}
+
+/// One recipient's weight in a fee split, in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSplit {
+    pub recipient: Address,
+    pub weight_bps: u32,
+}
+
+/// Preview of a payout under the current fee configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeQuote {
+    pub gross: i128,               // Amount deducted from the program balance
+    pub fee: i128,                 // Fee that would be charged
+    pub net: i128,                 // Amount the payout recipient would receive
+    pub recipients: Vec<FeeShare>, // Fee split shares, or all to fee_recipient; empty if no fee
+}
+
+/// One recipient's part of a collected fee, as listed in fee events.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeShare {
+    pub recipient: Address,
+    pub amount: i128,
 }
+
+// Maximum number of recipients in a fee split
+const MAX_FEE_SPLITS: u32 = 10;
+// ==================== EVENTS MODULE ====================
+mod events;
+
 // ==================== MONITORING MODULE ====================
 mod monitoring {
     use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
@@ -249,7 +287,8 @@ mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
                 operation,
@@ -273,7 +312,8 @@ mod monitoring {
             .persistent()
             .set(&time_key, &(total + duration));
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
                 function,
@@ -453,7 +493,8 @@ mod anti_abuse {
                     .last_operation_timestamp
                     .saturating_add(config.cooldown_period)
         {
-            env.events().publish(
+            crate::events::publish(
+                env,
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
@@ -472,7 +513,8 @@ mod anti_abuse {
         } else {
             // Same window
             if state.operation_count >= config.max_operations {
-                env.events().publish(
+                crate::events::publish(
+                    env,
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
@@ -623,6 +665,17 @@ pub struct ProgramScheduleReleased {
     pub release_type: ReleaseType,
 }
 
+/// Event emitted (and stored) when a program's funds are emergency-withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramEvacuated {
+    pub program_id: String,
+    pub amount: i128,
+    pub recipient: Address,
+    pub evacuated_by: Address,
+    pub evacuated_at: u64,
+}
+
 /// Complete program state and configuration.
 ///
 /// # Fields
@@ -634,11 +687,11 @@ pub struct ProgramScheduleReleased {
 /// * `token_address` - Token contract used for transfers
 ///
 /// # Storage
-/// Stored in instance storage with key `PROGRAM_DATA`.
+/// Stored in instance storage with key `DataKey::Program(program_id)`.
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
+/// - `remaining_balance = total_funds - sum(payout_history.amounts)` (zero once evacuated)
 /// - `payout_history` is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after init
 ///
@@ -660,7 +713,7 @@ pub struct ProgramScheduleReleased {
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
+/// - `remaining_balance = total_funds - sum(payout_history.amounts)` (zero once evacuated)
 /// - `payout_history` is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after registration
 #[contracttype]
@@ -683,6 +736,10 @@ pub enum DataKey {
     ReleaseHistory(String),       // program_id -> Vec<ProgramReleaseHistory>
     NextScheduleId(String),       // program_id -> next schedule_id
     IsPaused,                     // Global contract pause state
+    ProgramPaused(String),        // program_id -> Address that paused the program
+    Evacuation(String),           // program_id -> ProgramEvacuated
+    Version,                      // Current contract version
+    PreviousVersion,              // Version before the last upgrade
 }
 
 // ============================================================================
@@ -787,11 +844,35 @@ impl ProgramEscrowContract {
         Self::is_paused_internal(&env)
     }
 
-    /// Pause the contract (authorized payout key only)
-    /// Prevents new fund locking, payouts, and schedule releases
+    /// Check if a single program is paused (internal helper)
+    fn is_program_paused_internal(env: &Env, program_id: &String) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::ProgramPaused(program_id.clone()))
+    }
+
+    /// Require `caller` to be the contract admin or the program's payout key
+    fn require_admin_or_payout_key(env: &Env, program_data: &ProgramData, caller: &Address) {
+        caller.require_auth();
+
+        let is_admin = anti_abuse::get_admin(env)
+            .map(|admin| admin == *caller)
+            .unwrap_or(false);
+        if !is_admin && *caller != program_data.authorized_payout_key {
+            panic!("Unauthorized");
+        }
+    }
+
+    /// Get pause status of a single program (view function)
+    pub fn is_program_paused(env: Env, program_id: String) -> bool {
+        Self::is_program_paused_internal(&env, &program_id)
+    }
+
+    /// Pause the whole contract (admin only)
+    /// Prevents new fund locking, payouts, and schedule releases for every program
     pub fn pause(env: Env) {
-        // For program-escrow, pause is triggered by the first authorized key that calls it
-        // In a multi-program setup, this would need to be per-program
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
         if Self::is_paused_internal(&env) {
             return; // Already paused, idempotent
@@ -799,56 +880,172 @@ impl ProgramEscrowContract {
 
         env.storage().instance().set(&DataKey::IsPaused, &true);
 
-        env.events()
-            .publish((symbol_short!("pause"),), (env.ledger().timestamp(),));
+        events::publish(
+            &env,
+            (symbol_short!("pause"),),
+            (admin, env.ledger().timestamp()),
+        );
     }
 
-    /// Unpause the contract (authorized payout key only)
+    /// Unpause the whole contract (admin only)
     /// Resumes normal operations
     pub fn unpause(env: Env) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
         if !Self::is_paused_internal(&env) {
             return; // Already unpaused, idempotent
         }
 
         env.storage().instance().set(&DataKey::IsPaused, &false);
 
-        env.events()
-            .publish((symbol_short!("unpause"),), (env.ledger().timestamp(),));
+        events::publish(
+            &env,
+            (symbol_short!("unpause"),),
+            (admin, env.ledger().timestamp()),
+        );
     }
 
-    /// Emergency withdrawal for all contract funds (authorized payout key only, only when paused)
-    pub fn emergency_withdraw(env: Env, program_id: String, recipient: Address) -> i128 {
-        // Only allow emergency withdrawal when contract is paused
-        if !Self::is_paused_internal(&env) {
-            panic!("Contract must be paused for emergency withdrawal");
+    /// Pause a single program (admin or the program's payout key)
+    /// Prevents fund locking, payouts, and schedule releases for that program only.
+    /// The admin can take over a pause set by the payout key, so that only
+    /// the admin can lift it.
+    pub fn pause_program(env: Env, program_id: String, caller: Address) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::require_admin_or_payout_key(&env, &program_data, &caller);
+
+        let paused_by: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProgramPaused(program_id.clone()));
+        if let Some(paused_by) = paused_by {
+            // Already paused; only the admin taking over a payout key pause changes it
+            if paused_by == caller || caller == program_data.authorized_payout_key {
+                return;
+            }
         }
 
-        // Get program data to access token address
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData =
-            env.storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| {
-                    panic!("Program not found");
-                });
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramPaused(program_id.clone()), &caller);
+
+        events::publish(
+            &env,
+            (symbol_short!("prg_pause"), program_id),
+            (caller, env.ledger().timestamp()),
+        );
+    }
 
-        let client = token::Client::new(&env, &program_data.token_address);
-        let balance = client.balance(&env.current_contract_address());
+    /// Unpause a single program (admin, or the payout key if it set the pause)
+    pub fn unpause_program(env: Env, program_id: String, caller: Address) {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::require_admin_or_payout_key(&env, &program_data, &caller);
+
+        let paused_by: Address = match env
+            .storage()
+            .instance()
+            .get(&DataKey::ProgramPaused(program_id.clone()))
+        {
+            Some(paused_by) => paused_by,
+            None => return, // Already unpaused, idempotent
+        };
 
-        if balance <= 0 {
-            return 0; // No funds to withdraw
+        // A pause set by the admin can only be lifted by the admin
+        let is_admin = anti_abuse::get_admin(&env)
+            .map(|admin| admin == caller)
+            .unwrap_or(false);
+        if !is_admin && paused_by != caller {
+            panic!("Program paused by admin");
         }
 
-        // Transfer all funds to recipient
-        client.transfer(&env.current_contract_address(), &recipient, &balance);
+        env.storage()
+            .instance()
+            .remove(&DataKey::ProgramPaused(program_id.clone()));
 
-        env.events().publish(
-            (symbol_short!("ewith"),),
-            (balance, env.ledger().timestamp()),
+        events::publish(
+            &env,
+            (symbol_short!("prg_unpau"), program_id),
+            (caller, env.ledger().timestamp()),
         );
+    }
+
+    /// Emergency withdrawal of a single program's funds (admin or the
+    /// program's payout key, only while the contract or program is paused).
+    ///
+    /// Transfers the program's `remaining_balance` to `recipient`, leaving
+    /// other programs' funds untouched, and marks the program as evacuated
+    /// so it cannot be funded again.
+    pub fn emergency_withdraw(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        caller: Address,
+    ) -> i128 {
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::require_admin_or_payout_key(&env, &program_data, &caller);
+
+        // Only allow emergency withdrawal when contract or program is paused
+        if !Self::is_paused_internal(&env) && !Self::is_program_paused_internal(&env, &program_id) {
+            panic!("Contract or program must be paused for emergency withdrawal");
+        }
+
+        if Self::is_evacuated_internal(&env, &program_id) {
+            panic!("Program already evacuated");
+        }
+
+        let amount = program_data.remaining_balance;
+        program_data.remaining_balance = 0;
+        env.storage().instance().set(&program_key, &program_data);
 
-        balance
+        let record = ProgramEvacuated {
+            program_id: program_id.clone(),
+            amount,
+            recipient: recipient.clone(),
+            evacuated_by: caller,
+            evacuated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Evacuation(program_id.clone()), &record);
+
+        if amount > 0 {
+            let client = token::Client::new(&env, &program_data.token_address);
+            client.transfer(&env.current_contract_address(), &recipient, &amount);
+        }
+
+        events::publish(&env, (symbol_short!("ewith"), program_id), record);
+
+        amount
+    }
+
+    /// Check if a program has been evacuated (internal helper)
+    fn is_evacuated_internal(env: &Env, program_id: &String) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::Evacuation(program_id.clone()))
+    }
+
+    /// Get the emergency withdrawal record for a program, if any (view function)
+    pub fn get_evacuation(env: Env, program_id: String) -> Option<ProgramEvacuated> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Evacuation(program_id))
     }
 
     pub fn initialize_program(
@@ -886,14 +1083,21 @@ impl ProgramEscrowContract {
             token_address: token_address.clone(),
         };
 
-        // Initialize fee config with zero fees (disabled by default)
-        let fee_config = FeeConfig {
-            lock_fee_rate: 0,
-            payout_fee_rate: 0,
-            fee_recipient: authorized_payout_key.clone(),
-            fee_enabled: false,
-        };
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+        // Initialize fee config with zero fees (disabled by default); the
+        // config is contract-wide, so later programs must not reset it
+        if !env.storage().instance().has(&FEE_CONFIG) {
+            let fee_config = FeeConfig {
+                lock_fee_rate: 0,
+                payout_fee_rate: 0,
+                fee_recipient: authorized_payout_key.clone(),
+                fee_enabled: false,
+                fee_splits: vec![&env],
+            };
+            env.storage().instance().set(&FEE_CONFIG, &fee_config);
+        }
+        if !env.storage().instance().has(&DataKey::Version) {
+            env.storage().instance().set(&DataKey::Version, &VERSION);
+        }
 
         // Store program data
         env.storage().instance().set(&program_key, &program_data);
@@ -908,7 +1112,8 @@ impl ProgramEscrowContract {
         env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
 
         // Emit registration event
-        env.events().publish(
+        events::publish(
+            &env,
             (PROGRAM_REGISTERED,),
             (program_id, authorized_payout_key, token_address, 0i128),
         );
@@ -945,9 +1150,60 @@ impl ProgramEscrowContract {
                 payout_fee_rate: 0,
                 fee_recipient: env.current_contract_address(),
                 fee_enabled: false,
+                fee_splits: vec![env],
             })
     }
 
+    /// Divides a fee between its recipients (internal helper).
+    ///
+    /// Without a fee split the whole fee goes to `fee_recipient`; otherwise
+    /// it is divided by weight, with rounding dust going to the first
+    /// recipient. Returns no shares for a zero fee.
+    fn split_fee(env: &Env, fee_config: &FeeConfig, amount: i128) -> Vec<FeeShare> {
+        let mut shares: Vec<FeeShare> = vec![env];
+        if amount <= 0 {
+            return shares;
+        }
+        if fee_config.fee_splits.is_empty() {
+            shares.push_back(FeeShare {
+                recipient: fee_config.fee_recipient.clone(),
+                amount,
+            });
+        } else {
+            let mut distributed: i128 = 0;
+            for split in fee_config.fee_splits.iter() {
+                let share = Self::calculate_fee(amount, split.weight_bps as i128);
+                distributed += share;
+                shares.push_back(FeeShare {
+                    recipient: split.recipient,
+                    amount: share,
+                });
+            }
+            let mut first = shares.get(0).unwrap();
+            first.amount += amount - distributed;
+            shares.set(0, first);
+        }
+        shares
+    }
+
+    /// Pays a collected fee out of the contract to the fee recipients
+    /// (internal helper). Returns the shares paid (see `split_fee`).
+    fn distribute_fee(
+        env: &Env,
+        fee_config: &FeeConfig,
+        token_client: &token::Client,
+        amount: i128,
+    ) -> Vec<FeeShare> {
+        let shares = Self::split_fee(env, fee_config, amount);
+        let contract_address = env.current_contract_address();
+        for share in shares.iter() {
+            if share.amount > 0 {
+                token_client.transfer(&contract_address, &share.recipient, &share.amount);
+            }
+        }
+        shares
+    }
+
     /// Lock initial funds into the program escrow
     ///
     /// Lists all registered program IDs in the contract.
@@ -987,9 +1243,18 @@ impl ProgramEscrowContract {
 
     /// Locks funds into the program escrow for prize distribution.
     ///
+    /// Transfers `amount` of the program's token from `funder` into the
+    /// contract. When a lock fee is enabled it is paid out to the fee
+    /// recipient (or fee splits) and the rest is added to the program's
+    /// balance.
+    ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `program_id` - Program to fund
+    /// * `funder` - Address the tokens are pulled from (must authorize)
     /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
     ///
     /// # Returns
     /// * `ProgramData` - Updated program data with new balance
@@ -997,84 +1262,79 @@ impl ProgramEscrowContract {
     /// # Panics
     /// * If amount is zero or negative
     /// * If program is not initialized
+    /// * If the funder's token balance is insufficient
     ///
     /// # State Changes
-    /// - Increases `total_funds` by amount
-    /// - Increases `remaining_balance` by amount
+    /// - Transfers `amount` from `funder` to the contract
+    /// - Increases `total_funds` and `remaining_balance` by the net amount
     /// - Emits FundsLocked event
     ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
-    ///
     /// # Security Considerations
     /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
+    /// - Funder must sign; the recorded balance always matches tokens received
     /// - Multiple lock operations are additive (cumulative)
     ///
     /// # Events
-    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
+    /// Emits: `FundsLocked(program_id, funder, net_amount, new_remaining_balance)`
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::token;
-    ///
-    /// // 1. Transfer tokens to contract
     /// let amount = 10_000_0000000; // 10,000 USDC
-    /// token_client.transfer(
-    ///     &organizer,
-    ///     &contract_address,
-    ///     &amount
-    /// );
-    ///
-    /// // 2. Record the locked funds
-    /// let updated = escrow_client.lock_program_funds(&amount);
-    /// println!("Locked: {} USDC", amount / 10_000_000);
+    /// let updated = escrow_client.lock_program_funds(&program_id, &organizer, &amount, &None);
     /// println!("Remaining: {}", updated.remaining_balance);
     /// ```
     ///
     /// # Production Usage
     /// ```bash
-    /// # 1. Transfer USDC to contract
-    /// stellar contract invoke \
-    ///   --id USDC_TOKEN_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- transfer \
-    ///   --from ORGANIZER_ADDRESS \
-    ///   --to CONTRACT_ADDRESS \
-    ///   --amount 10000000000
-    ///
-    /// # 2. Record locked funds
     /// stellar contract invoke \
     ///   --id CONTRACT_ID \
     ///   --source ORGANIZER_KEY \
     ///   -- lock_program_funds \
+    ///   --program_id "Hackathon2024" \
+    ///   --funder ORGANIZER_ADDRESS \
     ///   --amount 10000000000
     /// ```
     ///
     /// # Gas Cost
-    /// Low - Storage update + event emission
-    ///
-    /// # Common Pitfalls
-    /// - Forgetting to transfer tokens before calling
-    /// -  Locking amount that exceeds actual contract balance
-    /// -  Not verifying contract received the tokens
-    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
+    /// Medium - Token transfer + storage update + event emission
+    pub fn lock_program_funds(
+        env: Env,
+        program_id: String,
+        funder: Address,
+        amount: i128,
+        correlation_id: Option<String>,
+    ) -> ProgramData {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::lock_program_funds_internal(env, program_id, funder, amount)
+        })
+    }
+
+    /// Implementation of `lock_program_funds`.
+    fn lock_program_funds_internal(
+        env: Env,
+        program_id: String,
+        funder: Address,
+        amount: i128,
+    ) -> ProgramData {
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+        anti_abuse::check_rate_limit(&env, funder.clone());
 
         let _start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
+        let caller = funder.clone();
 
         // Check if contract is paused
         if Self::is_paused_internal(&env) {
             monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
             panic!("Contract is paused");
         }
+        if Self::is_program_paused_internal(&env, &program_id) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            panic!("Program is paused");
+        }
+        if Self::is_evacuated_internal(&env, &program_id) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            panic!("Program has been evacuated");
+        }
 
         // Validate amount
         if amount <= 0 {
@@ -1093,6 +1353,12 @@ impl ProgramEscrowContract {
                 panic!("Program not found")
             });
 
+        funder.require_auth();
+
+        // Pull the full amount from the funder
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&funder, &env.current_contract_address(), &amount);
+
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
         let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
@@ -1106,15 +1372,18 @@ impl ProgramEscrowContract {
         program_data.total_funds += net_amount;
         program_data.remaining_balance += net_amount;
 
-        // Emit fee collected event if applicable
+        // Pay out the fee and emit fee collected event if applicable
         if fee_amount > 0 {
-            env.events().publish(
+            let shares = Self::distribute_fee(&env, &fee_config, &token_client, fee_amount);
+            events::publish(
+                &env,
                 (symbol_short!("fee"),),
                 (
                     symbol_short!("lock"),
                     fee_amount,
                     fee_config.lock_fee_rate,
                     fee_config.fee_recipient.clone(),
+                    shares,
                 ),
             );
         }
@@ -1123,10 +1392,12 @@ impl ProgramEscrowContract {
         env.storage().instance().set(&program_key, &program_data);
 
         // Emit FundsLocked event (with net amount after fee)
-        env.events().publish(
+        events::publish(
+            &env,
             (FUNDS_LOCKED,),
             (
                 program_data.program_id.clone(),
+                funder,
                 net_amount,
                 program_data.remaining_balance,
             ),
@@ -1145,6 +1416,8 @@ impl ProgramEscrowContract {
     /// * `env` - The contract environment
     /// * `recipients` - Vector of recipient addresses
     /// * `amounts` - Vector of amounts (must match recipients length)
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
     ///
     /// # Returns
     /// * `ProgramData` - Updated program data after payouts
@@ -1239,11 +1512,27 @@ impl ProgramEscrowContract {
         program_id: String,
         recipients: Vec<Address>,
         amounts: Vec<i128>,
+        correlation_id: Option<String>,
+    ) -> ProgramData {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::batch_payout_internal(env, program_id, recipients, amounts)
+        })
+    }
+
+    /// Implementation of `batch_payout`.
+    fn batch_payout_internal(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
     ) -> ProgramData {
         // Check if contract is paused
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
         }
+        if Self::is_program_paused_internal(&env, &program_id) {
+            panic!("Program is paused");
+        }
 
         // Apply rate limiting to the contract itself or the program
         // We can't easily get the caller here without getting program data first
@@ -1317,11 +1606,6 @@ impl ProgramEscrowContract {
             // Transfer net amount to recipient
             token_client.transfer(&contract_address, &recipient.clone(), &net_amount);
 
-            // Transfer fee to fee recipient if applicable
-            if fee_amount > 0 {
-                token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
-            }
-
             // Record payout (with net amount)
             let payout_record = PayoutRecord {
                 recipient: recipient.clone(),
@@ -1331,15 +1615,18 @@ impl ProgramEscrowContract {
             updated_history.push_back(payout_record);
         }
 
-        // Emit fee collected event if applicable
+        // Pay out the batch's fees in one pass and emit fee collected event
         if total_fees > 0 {
-            env.events().publish(
+            let shares = Self::distribute_fee(&env, &fee_config, &token_client, total_fees);
+            events::publish(
+                &env,
                 (symbol_short!("fee"),),
                 (
                     symbol_short!("payout"),
                     total_fees,
                     fee_config.payout_fee_rate,
                     fee_config.fee_recipient.clone(),
+                    shares,
                 ),
             );
         }
@@ -1353,7 +1640,8 @@ impl ProgramEscrowContract {
         env.storage().instance().set(&program_key, &updated_data);
 
         // Emit event
-        env.events().publish(
+        events::publish(
+            &env,
             (BATCH_PAYOUT,),
             (
                 program_id,
@@ -1372,6 +1660,8 @@ impl ProgramEscrowContract {
     /// * `env` - The contract environment
     /// * `recipient` - Address of the prize recipient
     /// * `amount` - Amount to transfer (in token's smallest denomination)
+    /// * `correlation_id` - Optional caller-supplied id (e.g. a GitHub delivery
+    ///   id) carried in the envelope of every event this call emits
     ///
     /// # Returns
     /// * `ProgramData` - Updated program data after payout
@@ -1424,11 +1714,27 @@ impl ProgramEscrowContract {
         program_id: String,
         recipient: Address,
         amount: i128,
+        correlation_id: Option<String>,
+    ) -> ProgramData {
+        events::with_correlation_id(&env.clone(), correlation_id, || {
+            Self::single_payout_internal(env, program_id, recipient, amount)
+        })
+    }
+
+    /// Implementation of `single_payout`.
+    fn single_payout_internal(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
     ) -> ProgramData {
         // Check if contract is paused
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
         }
+        if Self::is_program_paused_internal(&env, &program_id) {
+            panic!("Program is paused");
+        }
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
@@ -1476,16 +1782,18 @@ impl ProgramEscrowContract {
         let token_client = token::Client::new(&env, &program_data.token_address);
         token_client.transfer(&contract_address, &recipient, &net_amount);
 
-        // Transfer fee to fee recipient if applicable
+        // Pay out the fee to the fee recipients if applicable
         if fee_amount > 0 {
-            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
-            env.events().publish(
+            let shares = Self::distribute_fee(&env, &fee_config, &token_client, fee_amount);
+            events::publish(
+                &env,
                 (symbol_short!("fee"),),
                 (
                     symbol_short!("payout"),
                     fee_amount,
                     fee_config.payout_fee_rate,
                     fee_config.fee_recipient.clone(),
+                    shares,
                 ),
             );
         }
@@ -1511,7 +1819,8 @@ impl ProgramEscrowContract {
 
         // Emit Payout event (with net amount after fee)
         // Emit event
-        env.events().publish(
+        events::publish(
+            &env,
             (PAYOUT,),
             (
                 program_id,
@@ -1579,6 +1888,9 @@ impl ProgramEscrowContract {
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
         }
+        if Self::is_program_paused_internal(&env, &program_id) {
+            panic!("Program is paused");
+        }
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
@@ -1641,7 +1953,8 @@ impl ProgramEscrowContract {
         );
 
         // Emit program schedule created event
-        env.events().publish(
+        events::publish(
+            &env,
             (PROG_SCHEDULE_CREATED,),
             ProgramScheduleCreated {
                 program_id: program_id.clone(),
@@ -1704,6 +2017,9 @@ impl ProgramEscrowContract {
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
         }
+        if Self::is_program_paused_internal(&env, &program_id) {
+            panic!("Program is paused");
+        }
 
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
@@ -1784,7 +2100,8 @@ impl ProgramEscrowContract {
             .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
 
         // Emit program schedule released event
-        env.events().publish(
+        events::publish(
+            &env,
             (PROG_SCHEDULE_RELEASED,),
             ProgramScheduleReleased {
                 program_id: program_id.clone(),
@@ -1837,6 +2154,14 @@ impl ProgramEscrowContract {
     pub fn release_program_schedule_manual(env: Env, program_id: String, schedule_id: u64) {
         let start = env.ledger().timestamp();
 
+        // Check if contract or program is paused
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+        if Self::is_program_paused_internal(&env, &program_id) {
+            panic!("Program is paused");
+        }
+
         // Get program data
         let program_key = DataKey::Program(program_id.clone());
         let program_data: ProgramData = env
@@ -1917,7 +2242,8 @@ impl ProgramEscrowContract {
             .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
 
         // Emit program schedule released event
-        env.events().publish(
+        events::publish(
+            &env,
             (PROG_SCHEDULE_RELEASED,),
             ProgramScheduleReleased {
                 program_id: program_id.clone(),
@@ -2010,7 +2336,10 @@ impl ProgramEscrowContract {
         program_data.remaining_balance
     }
 
-    /// Update fee configuration (admin only - uses authorized_payout_key)
+    /// Update fee configuration (admin only)
+    ///
+    /// The fee configuration is contract-wide, so it is controlled by the
+    /// contract admin rather than by any single program's payout key.
     ///
     /// # Arguments
     /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
@@ -2024,16 +2353,8 @@ impl ProgramEscrowContract {
         fee_recipient: Option<Address>,
         fee_enabled: Option<bool>,
     ) {
-        // Verify authorization
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
-
-        // Note: In Soroban, we check authorization by requiring auth from the authorized key
-        // For this function, we'll require auth from the authorized_payout_key
-        program_data.authorized_payout_key.require_auth();
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
         let mut fee_config = Self::get_fee_config_internal(&env);
 
@@ -2068,7 +2389,8 @@ impl ProgramEscrowContract {
         env.storage().instance().set(&FEE_CONFIG, &fee_config);
 
         // Emit fee config updated event
-        env.events().publish(
+        events::publish(
+            &env,
             (symbol_short!("fee_cfg"),),
             (
                 fee_config.lock_fee_rate,
@@ -2084,6 +2406,88 @@ impl ProgramEscrowContract {
         Self::get_fee_config_internal(&env)
     }
 
+    /// Builds a payout quote for `amount` (internal helper).
+    /// Amounts that a payout would reject (zero or negative) quote as zero,
+    /// so one bad entry doesn't fail a whole batch quote.
+    fn quote_payout_internal(env: &Env, fee_config: &FeeConfig, amount: i128) -> FeeQuote {
+        if amount <= 0 {
+            return FeeQuote {
+                gross: amount,
+                fee: 0,
+                net: 0,
+                recipients: vec![env],
+            };
+        }
+        let fee = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.payout_fee_rate)
+        } else {
+            0
+        };
+        FeeQuote {
+            gross: amount,
+            fee,
+            net: amount - fee,
+            recipients: Self::split_fee(env, fee_config, fee),
+        }
+    }
+
+    /// Preview the fee and net amount of a `single_payout` (view function)
+    ///
+    /// # Arguments
+    /// * `amount` - Gross payout amount
+    pub fn quote_single_payout(env: Env, amount: i128) -> FeeQuote {
+        let fee_config = Self::get_fee_config_internal(&env);
+        Self::quote_payout_internal(&env, &fee_config, amount)
+    }
+
+    /// Preview the fee and net amount of each entry of a `batch_payout`
+    /// (view function)
+    ///
+    /// # Arguments
+    /// * `amounts` - Gross payout amounts, in batch order
+    pub fn quote_batch_payout(env: Env, amounts: Vec<i128>) -> Vec<FeeQuote> {
+        let fee_config = Self::get_fee_config_internal(&env);
+        let mut quotes = vec![&env];
+        for amount in amounts.iter() {
+            quotes.push_back(Self::quote_payout_internal(&env, &fee_config, amount));
+        }
+        quotes
+    }
+
+    /// Set the weighted list of fee recipients (admin only)
+    ///
+    /// Weights are in basis points and must sum to 10,000. Pass an empty list
+    /// to send all fees to `fee_recipient` again.
+    ///
+    /// # Arguments
+    /// * `splits` - Recipients and their weights (at most 10)
+    pub fn set_fee_splits(env: Env, splits: Vec<FeeSplit>) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if splits.len() > MAX_FEE_SPLITS {
+            panic!("Too many fee recipients: maximum is {}", MAX_FEE_SPLITS);
+        }
+        if !splits.is_empty() {
+            let mut total: u32 = 0;
+            for split in splits.iter() {
+                if split.weight_bps == 0 {
+                    panic!("Fee split weights must be greater than zero");
+                }
+                total = total.saturating_add(split.weight_bps);
+            }
+            if total as i128 != BASIS_POINTS {
+                panic!("Fee split weights must sum to {}", BASIS_POINTS);
+            }
+        }
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+        fee_config.fee_splits = splits.clone();
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        events::publish(&env, (symbol_short!("fee_split"),), splits);
+    }
+
     /// Gets the total number of programs registered.
     ///
     /// # Returns
@@ -2123,21 +2527,83 @@ impl ProgramEscrowContract {
     }
 
     // ========================================================================
-    // Anti-Abuse Administrative Functions
+    // Event Envelope
     // ========================================================================
 
-    /// Sets the administrative address for anti-abuse configuration.
-    /// Can only be called once or by the existing admin.
-    pub fn set_admin(env: Env, new_admin: Address) {
-        if let Some(current_admin) = anti_abuse::get_admin(&env) {
-            current_admin.require_auth();
-        }
-        anti_abuse::set_admin(&env, new_admin);
+    /// Gets the sequence number of the last event emitted.
+    pub fn get_event_sequence(env: Env) -> u64 {
+        events::get_sequence(&env)
     }
 
-    /// Updates the rate limit configuration.
-    /// Only the admin can call this.
-    pub fn update_rate_limit_config(
+    // ========================================================================
+    // Upgrades
+    // ========================================================================
+
+    /// Upgrades the contract to new WASM code (admin only).
+    ///
+    /// Programs, schedules and configuration live in contract storage and
+    /// survive the upgrade. New code must keep reading the current storage
+    /// layout until a migration entry point exists.
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - Hash of the uploaded WASM code
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        let current_version = Self::get_version(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::PreviousVersion, &current_version);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::publish(
+            &env,
+            (symbol_short!("upgraded"),),
+            (current_version, new_wasm_hash, env.ledger().timestamp()),
+        );
+    }
+
+    /// Gets the current contract version.
+    /// Contracts deployed before versioning report version 1.
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(1)
+    }
+
+    /// Gets the version recorded before the last upgrade.
+    pub fn get_previous_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::PreviousVersion)
+    }
+
+    // ========================================================================
+    // Anti-Abuse Administrative Functions
+    // ========================================================================
+
+    /// Sets the first contract admin. The admin must authorize the call.
+    ///
+    /// Call this in the same transaction that deploys the contract. Until an
+    /// admin is set, admin-only functions such as `upgrade` are unavailable.
+    pub fn init_admin(env: Env, admin: Address) {
+        if anti_abuse::get_admin(&env).is_some() {
+            panic!("Admin already set");
+        }
+        admin.require_auth();
+        anti_abuse::set_admin(&env, admin);
+    }
+
+    /// Hands the admin role to a new address.
+    /// Only the current admin can call this.
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let current_admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        current_admin.require_auth();
+        anti_abuse::set_admin(&env, new_admin);
+    }
+
+    /// Updates the rate limit configuration.
+    /// Only the admin can call this.
+    pub fn update_rate_limit_config(
         env: Env,
         window_size: u64,
         max_operations: u32,
@@ -2348,6 +2814,11 @@ mod test {
         token::Client::new(env, &token_address)
     }
 
+    // Test helper to mint tokens to a funder
+    fn mint(env: &Env, token: &token::Client, to: &Address, amount: i128) {
+        token::StellarAssetClient::new(env, &token.address).mint(to, &amount);
+    }
+
     // ========================================================================
     // Program Registration Tests
     // ========================================================================
@@ -2356,28 +2827,21 @@ mod test {
         env: &Env,
         client: &ProgramEscrowContractClient<'static>,
         authorized_key: &Address,
-        token: &Address,
         program_id: &String,
         total_amount: i128,
         winner: &Address,
         release_timestamp: u64,
     ) {
-        // Register program
-        client.initialize_program(program_id, authorized_key, token);
-
         // Create and fund token
         let token_client = create_token_contract(env, authorized_key);
         let token_admin = token::StellarAssetClient::new(env, &token_client.address);
         token_admin.mint(authorized_key, &total_amount);
 
+        // Register program
+        client.initialize_program(program_id, authorized_key, &token_client.address);
+
         // Lock funds for program
-        token_client.approve(
-            authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(program_id, &total_amount);
+        client.lock_program_funds(program_id, authorized_key, &total_amount, &None);
 
         // Create release schedule
         client.create_program_release_schedule(
@@ -2396,7 +2860,6 @@ mod test {
 
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2408,7 +2871,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2439,7 +2901,6 @@ mod test {
         let authorized_key = Address::generate(&env);
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 600_0000000;
         let amount2 = 400_0000000;
@@ -2447,22 +2908,16 @@ mod test {
 
         env.mock_all_auths();
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token);
-
         // Create and fund token
         let token_client = create_token_contract(&env, &authorized_key);
         let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
         token_admin.mint(&authorized_key, &total_amount);
 
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
+
         // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
+        client.lock_program_funds(&program_id, &authorized_key, &total_amount, &None);
 
         // Create first release schedule
         client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
@@ -2503,7 +2958,6 @@ mod test {
 
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2515,7 +2969,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2537,7 +2990,7 @@ mod test {
         let schedule = client.get_program_release_schedule(&program_id, &1);
         assert!(schedule.released);
         assert_eq!(schedule.released_at, Some(1001));
-        assert_eq!(schedule.released_by, Some(env.current_contract_address()));
+        assert_eq!(schedule.released_by, Some(contract_id));
 
         // Check no pending schedules
         let pending = client.get_pending_program_schedules(&program_id);
@@ -2559,7 +3012,6 @@ mod test {
 
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2571,7 +3023,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2605,7 +3056,6 @@ mod test {
         let authorized_key = Address::generate(&env);
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 600_0000000;
         let amount2 = 400_0000000;
@@ -2613,22 +3063,16 @@ mod test {
 
         env.mock_all_auths();
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token);
-
         // Create and fund token
         let token_client = create_token_contract(&env, &authorized_key);
         let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
         token_admin.mint(&authorized_key, &total_amount);
 
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
+
         // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
+        client.lock_program_funds(&program_id, &authorized_key, &total_amount, &None);
 
         // Create first schedule
         client.create_program_release_schedule(&program_id, &amount1, &1000, &winner1.clone());
@@ -2682,7 +3126,6 @@ mod test {
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
         let winner3 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 300_0000000;
         let amount2 = 300_0000000;
@@ -2692,22 +3135,16 @@ mod test {
 
         env.mock_all_auths();
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token);
-
         // Create and fund token
         let token_client = create_token_contract(&env, &authorized_key);
         let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
         token_admin.mint(&authorized_key, &total_amount);
 
+        // Register program
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
+
         // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
-        client.lock_program_funds(&program_id, &total_amount);
+        client.lock_program_funds(&program_id, &authorized_key, &total_amount, &None);
 
         // Create overlapping schedules (all at same timestamp)
         client.create_program_release_schedule(
@@ -2894,10 +3331,34 @@ mod test {
 
         // Lock funds
         let amount = 10_000_0000000i128; // 10,000 USDC
-        let updated = client.lock_program_funds(&prog_id, &amount);
+        mint(&env, &token_client, &backend, amount);
+        let updated = client.lock_program_funds(&prog_id, &backend, &amount, &None);
 
         assert_eq!(updated.total_funds, amount);
         assert_eq!(updated.remaining_balance, amount);
+
+        // Tokens were actually pulled from the funder
+        assert_eq!(token_client.balance(&backend), 0);
+        assert_eq!(token_client.balance(&contract_id), amount);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lock_funds_insufficient_funder_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        // Nothing minted: the transfer must fail and nothing is recorded
+        client.lock_program_funds(&prog_id, &backend, &1_000i128, &None);
     }
 
     #[test]
@@ -2924,8 +3385,10 @@ mod test {
         let amount1 = 5_000_0000000i128;
         let amount2 = 10_000_0000000i128;
 
-        client.lock_program_funds(&prog1, &amount1);
-        client.lock_program_funds(&prog2, &amount2);
+        mint(&env, &token_client, &backend1, amount1);
+        mint(&env, &token_client, &backend2, amount2);
+        client.lock_program_funds(&prog1, &backend1, &amount1, &None);
+        client.lock_program_funds(&prog2, &backend2, &amount2, &None);
 
         // Verify isolation - funds don't mix
         let info1 = client.get_program_info(&prog1);
@@ -2953,13 +3416,14 @@ mod test {
         client.initialize_program(&prog_id, &backend, &token_client.address);
 
         // Lock funds multiple times
-        client.lock_program_funds(&prog_id, &1_000_0000000);
-        client.lock_program_funds(&prog_id, &2_000_0000000);
-        client.lock_program_funds(&prog_id, &3_000_0000000);
+        mint(&env, &token_client, &backend, 60_000_000_000);
+        client.lock_program_funds(&prog_id, &backend, &10_000_000_000, &None);
+        client.lock_program_funds(&prog_id, &backend, &20_000_000_000, &None);
+        client.lock_program_funds(&prog_id, &backend, &30_000_000_000, &None);
 
         let info = client.get_program_info(&prog_id);
-        assert_eq!(info.total_funds, 6_000_0000000);
-        assert_eq!(info.remaining_balance, 6_000_0000000);
+        assert_eq!(info.total_funds, 60_000_000_000);
+        assert_eq!(info.remaining_balance, 60_000_000_000);
     }
 
     #[test]
@@ -2974,7 +3438,7 @@ mod test {
         let prog_id = String::from_str(&env, "Hackathon2024");
 
         client.initialize_program(&prog_id, &backend, &token);
-        client.lock_program_funds(&prog_id, &0);
+        client.lock_program_funds(&prog_id, &backend, &0, &None);
     }
 
     // ========================================================================
@@ -2996,12 +3460,13 @@ mod test {
         let prog_id = String::from_str(&env, "Test");
 
         client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &10_000_0000000);
+        mint(&env, &token_client, &backend, 100_000_000_000);
+        client.lock_program_funds(&prog_id, &backend, &100_000_000_000, &None);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
-        let amounts = soroban_sdk::vec![&env, 1_000_0000000i128]; // Mismatch!
+        let amounts = soroban_sdk::vec![&env, 10_000_000_000i128]; // Mismatch!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &recipients, &amounts, &None);
     }
 
     #[test]
@@ -3019,12 +3484,13 @@ mod test {
         let prog_id = String::from_str(&env, "Test");
 
         client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &5_000_0000000);
+        mint(&env, &token_client, &backend, 50_000_000_000);
+        client.lock_program_funds(&prog_id, &backend, &50_000_000_000, &None);
 
         let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
-        let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
+        let amounts = soroban_sdk::vec![&env, 100_000_000_000i128]; // More than available!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &recipients, &amounts, &None);
     }
 
     #[test]
@@ -3062,7 +3528,7 @@ mod test {
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
+        client.init_admin(&admin);
         client.update_rate_limit_config(&3600, &10, &60);
 
         let backend = Address::generate(&env);
@@ -3086,7 +3552,7 @@ mod test {
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
+        client.init_admin(&admin);
         client.update_rate_limit_config(&3600, &2, &0); // 2 ops max, no cooldown
 
         let backend = Address::generate(&env);
@@ -3107,7 +3573,7 @@ mod test {
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
+        client.init_admin(&admin);
         client.update_rate_limit_config(&3600, &1, &60); // 1 op max
 
         let backend = Address::generate(&env);
@@ -3128,7 +3594,7 @@ mod test {
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
+        client.init_admin(&admin);
 
         client.update_rate_limit_config(&7200, &5, &120);
 
@@ -3137,4 +3603,482 @@ mod test {
         assert_eq!(config.max_operations, 5);
         assert_eq!(config.cooldown_period, 120);
     }
+
+    #[test]
+    fn test_batch_payout_fee_split() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init_admin(&admin);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        token_admin.mint(&backend, &10_000);
+        client.lock_program_funds(&prog_id, &backend, &10_000, &None);
+
+        // 10% payout fee, split 70/30 between treasury and partner
+        let treasury = Address::generate(&env);
+        let partner = Address::generate(&env);
+        client.update_fee_config(&None, &Some(1_000), &None, &Some(true));
+        client.set_fee_splits(&vec![
+            &env,
+            FeeSplit {
+                recipient: treasury.clone(),
+                weight_bps: 7_000,
+            },
+            FeeSplit {
+                recipient: partner.clone(),
+                weight_bps: 3_000,
+            },
+        ]);
+
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        client.batch_payout(
+            &prog_id,
+            &vec![&env, winner1.clone(), winner2.clone()],
+            &vec![&env, 1_000, 1_000],
+            &None,
+        );
+
+        assert_eq!(token_client.balance(&winner1), 900);
+        assert_eq!(token_client.balance(&winner2), 900);
+        assert_eq!(token_client.balance(&treasury), 140);
+        assert_eq!(token_client.balance(&partner), 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fee split weights must sum to 10000")]
+    fn test_fee_split_weights_must_sum_to_10000() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        client.init_admin(&Address::generate(&env));
+
+        client.set_fee_splits(&vec![
+            &env,
+            FeeSplit {
+                recipient: Address::generate(&env),
+                weight_bps: 5_000,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_quote_payouts() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        client.init_admin(&Address::generate(&env));
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        client.initialize_program(&String::from_str(&env, "P1"), &backend, &token);
+
+        // Fees disabled: net equals gross
+        let quote = client.quote_single_payout(&1_000);
+        assert_eq!(quote.fee, 0);
+        assert_eq!(quote.net, 1_000);
+        assert_eq!(quote.recipients.len(), 0);
+
+        // 5% payout fee
+        client.update_fee_config(&None, &Some(500), &None, &Some(true));
+
+        let quotes = client.quote_batch_payout(&vec![&env, 1_000, 333, 0]);
+        assert_eq!(quotes.len(), 3);
+        assert_eq!(quotes.get(0).unwrap().fee, 50);
+        assert_eq!(quotes.get(0).unwrap().net, 950);
+        assert_eq!(quotes.get(1).unwrap().fee, 16);
+        assert_eq!(quotes.get(1).unwrap().net, 317);
+        // Entries a payout would reject quote as zero instead of failing
+        assert_eq!(quotes.get(2).unwrap().fee, 0);
+        assert_eq!(quotes.get(2).unwrap().net, 0);
+        assert_eq!(client.quote_single_payout(&-5).net, 0);
+        // Without a split the whole fee goes to the fee recipient
+        assert_eq!(
+            quotes.get(0).unwrap().recipients,
+            vec![
+                &env,
+                FeeShare {
+                    recipient: backend.clone(),
+                    amount: 50,
+                },
+            ]
+        );
+
+        // With a split the quote lists every recipient's share
+        let treasury = Address::generate(&env);
+        let partner = Address::generate(&env);
+        client.set_fee_splits(&vec![
+            &env,
+            FeeSplit {
+                recipient: treasury.clone(),
+                weight_bps: 7_000,
+            },
+            FeeSplit {
+                recipient: partner.clone(),
+                weight_bps: 3_000,
+            },
+        ]);
+        let quote = client.quote_single_payout(&1_000);
+        assert_eq!(
+            quote.recipients,
+            vec![
+                &env,
+                FeeShare {
+                    recipient: treasury,
+                    amount: 35,
+                },
+                FeeShare {
+                    recipient: partner,
+                    amount: 15,
+                },
+            ]
+        );
+    }
+
+    // ========================================================================
+    // Pause Tests
+    // ========================================================================
+
+    fn setup_two_funded_programs(
+        env: &Env,
+    ) -> (
+        ProgramEscrowContractClient<'_>,
+        token::Client<'_>,
+        Address,
+        Address,
+        Address,
+    ) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        client.init_admin(&admin);
+        let token_client = create_token_contract(env, &admin);
+
+        let backend_a = Address::generate(env);
+        let backend_b = Address::generate(env);
+        let prog_a = String::from_str(env, "ProgA");
+        let prog_b = String::from_str(env, "ProgB");
+        client.initialize_program(&prog_a, &backend_a, &token_client.address);
+        client.initialize_program(&prog_b, &backend_b, &token_client.address);
+
+        mint(env, &token_client, &backend_a, 10_000);
+        mint(env, &token_client, &backend_b, 10_000);
+        client.lock_program_funds(&prog_a, &backend_a, &10_000, &None);
+        client.lock_program_funds(&prog_b, &backend_b, &10_000, &None);
+
+        (client, token_client, admin, backend_a, backend_b)
+    }
+
+    #[test]
+    fn test_pause_program_only_affects_that_program() {
+        let env = Env::default();
+        let (client, token_client, _, backend_a, _) = setup_two_funded_programs(&env);
+        let prog_a = String::from_str(&env, "ProgA");
+        let prog_b = String::from_str(&env, "ProgB");
+        let winner = Address::generate(&env);
+
+        client.pause_program(&prog_a, &backend_a);
+        assert!(client.is_program_paused(&prog_a));
+        assert!(!client.is_program_paused(&prog_b));
+        assert!(!client.is_paused());
+
+        assert!(client
+            .try_single_payout(&prog_a, &winner, &1_000, &None)
+            .is_err());
+        assert!(client
+            .try_lock_program_funds(&prog_a, &backend_a, &1_000, &None)
+            .is_err());
+
+        client.single_payout(&prog_b, &winner, &1_000, &None);
+        assert_eq!(token_client.balance(&winner), 1_000);
+
+        client.unpause_program(&prog_a, &backend_a);
+        client.single_payout(&prog_a, &winner, &1_000, &None);
+        assert_eq!(token_client.balance(&winner), 2_000);
+    }
+
+    #[test]
+    fn test_paused_program_blocks_schedule_release() {
+        let env = Env::default();
+        let (client, _, _, backend_a, _) = setup_two_funded_programs(&env);
+        let prog_a = String::from_str(&env, "ProgA");
+        let winner = Address::generate(&env);
+
+        let release_at = env.ledger().timestamp() + 100;
+        client.create_program_release_schedule(&prog_a, &1_000, &release_at, &winner);
+        env.ledger().set_timestamp(release_at + 1);
+
+        client.pause_program(&prog_a, &backend_a);
+        assert!(client
+            .try_release_prog_schedule_automatic(&prog_a, &1)
+            .is_err());
+        assert!(client
+            .try_release_program_schedule_manual(&prog_a, &1)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn test_pause_program_rejects_other_programs_key() {
+        let env = Env::default();
+        let (client, _, _, _, backend_b) = setup_two_funded_programs(&env);
+
+        client.pause_program(&String::from_str(&env, "ProgA"), &backend_b);
+    }
+
+    #[test]
+    fn test_admin_pause_controls() {
+        let env = Env::default();
+        let (client, _, admin, _, _) = setup_two_funded_programs(&env);
+        let prog_b = String::from_str(&env, "ProgB");
+
+        client.pause_program(&prog_b, &admin);
+        assert!(client.is_program_paused(&prog_b));
+
+        client.pause();
+        assert!(client.is_paused());
+        client.unpause();
+        assert!(!client.is_paused());
+    }
+
+    #[test]
+    fn test_payout_key_cannot_lift_admin_pause() {
+        let env = Env::default();
+        let (client, _, admin, backend_a, _) = setup_two_funded_programs(&env);
+        let prog_a = String::from_str(&env, "ProgA");
+
+        client.pause_program(&prog_a, &admin);
+        assert!(client.try_unpause_program(&prog_a, &backend_a).is_err());
+        assert!(client.is_program_paused(&prog_a));
+
+        client.unpause_program(&prog_a, &admin);
+        assert!(!client.is_program_paused(&prog_a));
+    }
+
+    #[test]
+    fn test_admin_takes_over_payout_key_pause() {
+        let env = Env::default();
+        let (client, _, admin, backend_a, _) = setup_two_funded_programs(&env);
+        let prog_a = String::from_str(&env, "ProgA");
+
+        client.pause_program(&prog_a, &backend_a);
+        client.pause_program(&prog_a, &admin);
+        assert!(client.try_unpause_program(&prog_a, &backend_a).is_err());
+
+        client.unpause_program(&prog_a, &admin);
+        client.pause_program(&prog_a, &backend_a);
+        client.unpause_program(&prog_a, &backend_a);
+        assert!(!client.is_program_paused(&prog_a));
+    }
+
+    #[test]
+    fn test_global_pause_requires_admin_auth() {
+        let env = Env::default();
+        let (client, _, _, _, _) = setup_two_funded_programs(&env);
+
+        env.set_auths(&[]);
+        assert!(client.try_pause().is_err());
+        assert!(!client.is_paused());
+    }
+
+    #[test]
+    fn test_emergency_withdraw_only_takes_program_balance() {
+        let env = Env::default();
+        let (client, token_client, _, backend_a, _) = setup_two_funded_programs(&env);
+        let prog_a = String::from_str(&env, "ProgA");
+        let prog_b = String::from_str(&env, "ProgB");
+        let winner = Address::generate(&env);
+        let safe = Address::generate(&env);
+
+        client.single_payout(&prog_a, &winner, &4_000, &None);
+        client.pause_program(&prog_a, &backend_a);
+
+        let withdrawn = client.emergency_withdraw(&prog_a, &safe, &backend_a);
+        assert_eq!(withdrawn, 6_000);
+        assert_eq!(token_client.balance(&safe), 6_000);
+        assert_eq!(client.get_remaining_balance(&prog_a), 0);
+
+        // Program B's funds stay in the contract
+        assert_eq!(client.get_remaining_balance(&prog_b), 10_000);
+        assert_eq!(token_client.balance(&client.address), 10_000);
+
+        let record = client.get_evacuation(&prog_a).unwrap();
+        assert_eq!(record.amount, 6_000);
+        assert_eq!(record.recipient, safe);
+        assert_eq!(record.evacuated_by, backend_a);
+
+        // Evacuated programs cannot be withdrawn from or funded again
+        assert!(client
+            .try_emergency_withdraw(&prog_a, &safe, &backend_a)
+            .is_err());
+        client.unpause_program(&prog_a, &backend_a);
+        assert!(client
+            .try_lock_program_funds(&prog_a, &backend_a, &1_000, &None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_admin_emergency_withdraw_under_global_pause() {
+        let env = Env::default();
+        let (client, token_client, admin, _, _) = setup_two_funded_programs(&env);
+        let prog_b = String::from_str(&env, "ProgB");
+        let safe = Address::generate(&env);
+
+        client.pause();
+        assert_eq!(client.emergency_withdraw(&prog_b, &safe, &admin), 10_000);
+        assert_eq!(token_client.balance(&safe), 10_000);
+        assert!(client
+            .get_evacuation(&String::from_str(&env, "ProgA"))
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn test_emergency_withdraw_rejects_other_programs_key() {
+        let env = Env::default();
+        let (client, _, _, _, backend_b) = setup_two_funded_programs(&env);
+
+        client.pause();
+        client.emergency_withdraw(&String::from_str(&env, "ProgA"), &backend_b, &backend_b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract or program must be paused for emergency withdrawal")]
+    fn test_emergency_withdraw_requires_pause() {
+        let env = Env::default();
+        let (client, _, _, backend_a, _) = setup_two_funded_programs(&env);
+
+        client.emergency_withdraw(&String::from_str(&env, "ProgA"), &backend_a, &backend_a);
+    }
+
+    // ========================================================================
+    // Upgrade Tests
+    // ========================================================================
+
+    #[test]
+    fn test_programs_survive_upgrade() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.init_admin(&admin);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        token_admin.mint(&backend, &10_000);
+        client.lock_program_funds(&prog_id, &backend, &10_000, &None);
+        assert_eq!(client.get_version(), 1);
+
+        // Native test contracts dispatch by address, so swapping in an empty
+        // WASM exercises the upgrade path while keeping the contract callable.
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(soroban_sdk::Bytes::new(&env));
+        client.upgrade(&wasm_hash);
+        assert_eq!(client.get_previous_version(), Some(1));
+        assert_eq!(client.get_version(), 1);
+
+        let program = client.get_program_info(&prog_id);
+        assert_eq!(program.remaining_balance, 10_000);
+        assert_eq!(program.authorized_payout_key, backend);
+
+        let winner = Address::generate(&env);
+        client.single_payout(&prog_id, &winner, &4_000, &None);
+        assert_eq!(token_client.balance(&winner), 4_000);
+    }
+
+    #[test]
+    fn test_admin_cannot_be_claimed_through_set_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(soroban_sdk::Bytes::new(&env));
+
+        // Without an initialized admin, neither the handover nor upgrades work
+        assert!(client.try_set_admin(&Address::generate(&env)).is_err());
+        assert!(client.try_upgrade(&wasm_hash).is_err());
+
+        client.init_admin(&Address::generate(&env));
+        assert!(client.try_init_admin(&Address::generate(&env)).is_err());
+    }
+
+    // ========================================================================
+    // Event Envelope Tests
+    // ========================================================================
+
+    #[test]
+    fn test_events_carry_envelope() {
+        use soroban_sdk::{testutils::Events, TryFromVal, Val};
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.init_admin(&admin);
+        let token_client = create_token_contract(&env, &admin);
+
+        let envelopes_after = |after: u64| {
+            let mut envelopes = Vec::<events::EventEnvelope>::new(&env);
+            for (contract, _, data) in env.events().all().iter() {
+                if contract != contract_id {
+                    continue;
+                }
+                let (envelope, _payload) =
+                    <(events::EventEnvelope, Val)>::try_from_val(&env, &data).unwrap();
+                if envelope.sequence > after {
+                    envelopes.push_back(envelope);
+                }
+            }
+            envelopes
+        };
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "P1");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        mint(&env, &token_client, &backend, 1_000);
+
+        // The supplied id tags every event of the lock, and only those
+        let delivery_id = String::from_str(&env, "72d3162e-cc78-11e3-81ab");
+        let before_lock = client.get_event_sequence();
+        client.lock_program_funds(&prog_id, &backend, &1_000, &Some(delivery_id.clone()));
+        let mut sequence = before_lock;
+        for envelope in envelopes_after(before_lock).iter() {
+            assert_eq!(envelope.schema_version, events::EVENT_SCHEMA_VERSION);
+            assert_eq!(envelope.contract_kind, symbol_short!("program"));
+            assert_eq!(envelope.correlation_id, Some(delivery_id.clone()));
+            assert_eq!(envelope.sequence, sequence + 1);
+            sequence = envelope.sequence;
+        }
+        assert!(sequence > before_lock);
+        assert_eq!(client.get_event_sequence(), sequence);
+
+        client.single_payout(&prog_id, &Address::generate(&env), &100, &None);
+        let payout_events = envelopes_after(sequence);
+        assert!(!payout_events.is_empty());
+        for envelope in payout_events.iter() {
+            assert_eq!(envelope.correlation_id, None);
+        }
+    }
 }